@@ -1,13 +1,40 @@
-use crate::features::client::{RedisClientManagerState, RedisInfoDict, RedisKeyType};
-use crate::features::command::{Guid, RedisHScanResultItem, TTL};
+use crate::command::connections::{
+    invoke_get_connection_info, invoke_get_protobuf_schemas, invoke_remember_connection_db,
+};
+use crate::features::bytes::RedisBytes;
+use crate::features::cache::FileCacheManagerState;
+use crate::features::client::{
+    RedisClientManager, RedisClientManagerState, RedisInfoDict, RedisKeyType, RedisScannerKind,
+    RedisScannerPage,
+};
+use crate::features::command::{
+    ConnectionAddress, Guid, RedisHScanResultItem, RedisHashFormattedField, RedisKeyNamespaceNode,
+    RedisKeyNamespaceNodeKind, RedisKeyNamespacePage, RedisKeyspaceInfo, RedisListPage,
+    RedisScanFilter, RedisScanItems, RedisScanPage, RedisScannerOptions, RedisStreamAutoClaimQuery,
+    RedisStreamAutoClaimResult, RedisStreamConsumerInfo, RedisStreamEntry, RedisStreamField,
+    RedisStreamGroupInfo, RedisStreamPage, RedisStreamPendingEntry, RedisStreamPendingQuery,
+    RedisStreamPendingSummary, RedisZSetMember, RedisZSetPage, RedisZSetRangeQuery, ScanId,
+    ValueFormatOptions, ValueFormatters, TTL,
+};
 use crate::features::error::{Error, Result};
+use crate::features::formatter::FormatterRegistryState;
 use crate::features::response::Response;
+use crate::utils::calculator::calc_glob_prefix_pattern;
 use crate::utils::config::get_redis_max_db_nums;
-use fred::interfaces::{ClientLike, HashesInterface, KeysInterface, ServerInterface};
-use fred::types::{CustomCommand, InfoKind, RedisValue};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use fred::interfaces::{
+    ClientLike, HashesInterface, KeysInterface, ListInterface, RedisJsonInterface, SetsInterface,
+    SortedSetsInterface, StreamsInterface,
+};
+use fred::types::{
+    InfoKind, ListLocation, RedisMap, RedisValue, SetOptions, XPendingArgs, ZRange, ZRangeBound,
+    ZRangeKind, ZSort,
+};
 use std::collections::HashMap;
 use tauri::State;
 use tauri_plugin_tauri_redis_setting::SettingsManager;
+use tauri_redis_core::formatter::{FormattedValue, NO_COMPRESSION};
 
 #[tauri::command]
 pub async fn db_nums(
@@ -15,33 +42,123 @@ pub async fn db_nums(
     guid: Guid,
 ) -> Result<Response<u8>> {
     let mut lock = redis_client_manager.lock().await;
-    let _ = lock
+    let conn = lock
         .get_mut(&guid)
         .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
         .conn()?;
 
-    let nums = get_redis_max_db_nums()?;
+    // Only db 0 is available in a cluster.
+    if conn.is_clustered() {
+        return Ok(Response::success(Some(1), None));
+    }
+
+    // CONFIG may be disabled or renamed on managed services.
+    let nums = conn
+        .config_get::<HashMap<String, String>, _>("databases")
+        .await
+        .ok()
+        .and_then(|config| config.get("databases")?.parse::<u64>().ok())
+        .filter(|nums| *nums > 0)
+        .map(|nums| nums.min(u8::MAX as u64) as u8);
+    let nums = match nums {
+        Some(nums) => nums,
+        None => get_redis_max_db_nums()?,
+    };
 
     Ok(Response::success(Some(nums), None))
 }
 
 #[tauri::command]
-pub async fn switch_db(
+pub async fn keyspace_summary(
     redis_client_manager: State<'_, RedisClientManagerState>,
     guid: Guid,
-    db: u8,
-) -> Result<Response<()>> {
+) -> Result<Response<Vec<RedisKeyspaceInfo>>> {
     let mut lock = redis_client_manager.lock().await;
     let conn = lock
         .get_mut(&guid)
         .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
         .conn()?;
 
-    conn.select(db).await.map_err(Error::RedisInternalError)?;
+    if !conn.is_clustered() {
+        let info: String = conn
+            .info(Some(InfoKind::Keyspace))
+            .await
+            .map_err(Error::RedisInternalError)?;
+
+        return Ok(Response::success(
+            Some(RedisInfoDict::new(info).keyspace()),
+            None,
+        ));
+    }
+
+    // Every primary only holds its own slots.
+    let primaries = conn.cluster_primaries();
+    let mut summary = RedisKeyspaceInfo {
+        db: 0,
+        ..Default::default()
+    };
+    for primary in primaries {
+        let info: String = conn
+            .cluster_node_info(primary, Some(InfoKind::Keyspace))
+            .await
+            .map_err(Error::RedisInternalError)?;
+
+        if let Some(found) = RedisInfoDict::new(info)
+            .keyspace()
+            .into_iter()
+            .find(|item| item.db == 0)
+        {
+            summary.avg_ttl = (summary.avg_ttl * summary.keys + found.avg_ttl * found.keys)
+                .checked_div(summary.keys + found.keys)
+                .unwrap_or_default();
+            summary.keys += found.keys;
+            summary.expires += found.expires;
+        }
+    }
+
+    let summary = if summary.keys > 0 {
+        vec![summary]
+    } else {
+        vec![]
+    };
+
+    Ok(Response::success(Some(summary), None))
+}
+
+#[tauri::command]
+pub async fn switch_db(
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    db: u8,
+) -> Result<Response<()>> {
+    let mut lock = redis_client_manager.lock().await;
+    lock.get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .select_db(db)
+        .await?;
+    drop(lock);
+
+    // Restored on the next connect.
+    invoke_remember_connection_db(&file_cache_manager, &guid, db).await?;
 
     Ok(Response::default())
 }
 
+#[tauri::command]
+pub async fn current_db(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+) -> Result<Response<u8>> {
+    let lock = redis_client_manager.lock().await;
+    let db = lock
+        .get(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .db();
+
+    Ok(Response::success(Some(db), None))
+}
+
 #[tauri::command]
 pub async fn flush_all(
     redis_client_manager: State<'_, RedisClientManagerState>,
@@ -51,7 +168,7 @@ pub async fn flush_all(
     let conn = lock
         .get_mut(&guid)
         .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
-        .conn()?;
+        .writable_conn()?;
 
     conn.flushall(true)
         .await
@@ -92,12 +209,45 @@ pub async fn list_client_metrics(
     Ok(Response::success(Some(result), None))
 }
 
+#[tauri::command]
+pub async fn get_master_address(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+) -> Result<Response<ConnectionAddress>> {
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .conn()?;
+
+    if !conn.client_config().server.is_sentinel() {
+        return Err(Error::NotASentinelConnection);
+    }
+
+    // A sentinel client only keeps the connection to the current master.
+    let master = conn
+        .active_connections()
+        .await
+        .map_err(Error::RedisInternalError)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::FailedToResolveSentinelMaster)?;
+
+    Ok(Response::success(
+        Some(ConnectionAddress {
+            host: master.host.to_string(),
+            port: master.port,
+        }),
+        None,
+    ))
+}
+
 #[tauri::command]
 pub async fn list_all_keys(
     redis_client_manager: State<'_, RedisClientManagerState>,
     guid: Guid,
     condition_part: Option<String>,
-) -> Result<Response<Vec<String>>> {
+) -> Result<Response<Vec<RedisBytes>>> {
     let mut lock = redis_client_manager.lock().await;
     let conn = lock
         .get_mut(&guid)
@@ -109,22 +259,17 @@ pub async fn list_all_keys(
     // And the Type Conversion may throw errors.
     // let res: Vec<String> = conn
     let res: RedisValue = conn
-        .custom(
-            CustomCommand::new("KEYS", None, false),
-            vec![condition_part
-                .and_then(|part| if part.is_empty() { None } else { Some(part) })
-                .map_or_else(|| "*".to_string(), |part| "*".to_string() + &part + "*")],
-        )
+        .keys(RedisScanFilter::default().pattern(condition_part))
         .await
         .map_err(Error::RedisInternalError)?;
 
     // let non_string_vec: Vec<RedisValue> = res.into_array().into_iter().filter(|r| !r.is_string()).collect();
     // dbg!(&non_string_vec);
 
-    let result: Vec<String> = res
+    let result: Vec<RedisBytes> = res
         .into_array()
         .into_iter()
-        .filter_map(|item| item.into_string())
+        .filter_map(RedisBytes::from_value)
         .collect();
 
     Ok(Response::success(Some(result), None))
@@ -137,35 +282,50 @@ pub async fn scan_all_keys(
     guid: Guid,
     condition_part: Option<String>,
     force_new: Option<bool>,
-) -> Result<Response<Vec<String>>> {
+    scan_id: Option<ScanId>,
+    filter: Option<RedisScanFilter>,
+) -> Result<Response<Vec<RedisBytes>>> {
+    let scan_id = scan_id.unwrap_or_else(|| RedisScannerKind::Keys.default_scan_id());
+
     let mut lock = redis_client_manager.lock().await;
 
     let manager = lock
         .get_mut(&guid)
         .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?;
 
-    let pattern = condition_part
-        .and_then(|part| if part.is_empty() { None } else { Some(part) })
-        .map_or_else(|| "*".to_string(), |part| "*".to_string() + &part + "*");
+    let filter = filter.unwrap_or_default();
+    let pattern = filter.pattern(condition_part);
+    let r#type = filter.scan_type()?;
+    let regex = filter.regex()?;
 
     let settings_lock = settings_manager.read().await;
     let redis_each_scan_count: u32 = settings_lock.get_de("redisEachScanCount").unwrap();
 
     let scan_result = if force_new.is_some_and(|f| f) {
         manager
-            .force_new_scan(pattern, redis_each_scan_count, redis_each_scan_count, None)
+            .force_new_scan(
+                &scan_id,
+                pattern,
+                redis_each_scan_count,
+                redis_each_scan_count,
+                r#type,
+                regex,
+            )
             .await?
     } else {
         manager
-            .scan(pattern, redis_each_scan_count, redis_each_scan_count, None)
+            .scan(
+                &scan_id,
+                pattern,
+                redis_each_scan_count,
+                redis_each_scan_count,
+                r#type,
+                regex,
+            )
             .await?
     };
 
-    let result = scan_result
-        .keys()
-        .iter()
-        .filter_map(|key| key.as_str().and_then(|str| Some(str.to_string())))
-        .collect();
+    let result = scan_result.keys().iter().map(RedisBytes::from).collect();
 
     Ok(Response::success(Some(result), None))
 }
@@ -177,38 +337,138 @@ pub async fn refresh_scanned_all_keys(
     guid: Guid,
     condition_part: Option<String>,
     offset: Option<u32>,
-) -> Result<Response<Vec<String>>> {
+    scan_id: Option<ScanId>,
+    filter: Option<RedisScanFilter>,
+) -> Result<Response<Vec<RedisBytes>>> {
+    let scan_id = scan_id.unwrap_or_else(|| RedisScannerKind::Keys.default_scan_id());
+
     let mut lock = redis_client_manager.lock().await;
 
     let manager = lock
         .get_mut(&guid)
         .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?;
 
-    let pattern = condition_part
-        .and_then(|part| if part.is_empty() { None } else { Some(part) })
-        .map_or_else(|| "*".to_string(), |part| "*".to_string() + &part + "*");
+    let filter = filter.unwrap_or_default();
+    let pattern = filter.pattern(condition_part);
+    let r#type = filter.scan_type()?;
+    let regex = filter.regex()?;
 
     let settings_lock = settings_manager.read().await;
     let redis_each_scan_count: u32 = settings_lock.get_de("redisEachScanCount").unwrap();
 
     let scan_result = manager
-        .refresh_scan(pattern, redis_each_scan_count, offset, None)
+        .refresh_scan(
+            &scan_id,
+            pattern,
+            redis_each_scan_count,
+            offset,
+            r#type,
+            regex,
+        )
         .await?;
 
-    let result = scan_result
-        .keys()
-        .iter()
-        .filter_map(|key| key.as_str().and_then(|str| Some(str.to_string())))
-        .collect();
+    let result = scan_result.keys().iter().map(RedisBytes::from).collect();
 
     Ok(Response::success(Some(result), None))
 }
 
+// Not to clobber the scanner of the key list,
+// each prefix gets its own one so the expanded levels can be continued separately.
+const KEY_NAMESPACE_TREE_SCAN_ID: &str = "namespace";
+
+#[tauri::command]
+pub async fn scan_key_namespace_tree(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    settings_manager: State<'_, SettingsManager>,
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    guid: Guid,
+    prefix: Option<RedisBytes>,
+    force_new: Option<bool>,
+    scan_id: Option<ScanId>,
+) -> Result<Response<RedisKeyNamespacePage>> {
+    let separator = invoke_get_connection_info(&file_cache_manager, &guid)
+        .await?
+        .separator;
+    let prefix = prefix.unwrap_or_default();
+    let scan_id = scan_id.unwrap_or_else(|| {
+        format!(
+            "{}:{}",
+            KEY_NAMESPACE_TREE_SCAN_ID,
+            BASE64.encode(prefix.as_bytes())
+        )
+    });
+
+    let mut lock = redis_client_manager.lock().await;
+
+    let manager = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?;
+
+    let pattern = calc_glob_prefix_pattern(prefix.as_bytes());
+
+    let settings_lock = settings_manager.read().await;
+    let redis_each_scan_count: u32 = settings_lock.get_de("redisEachScanCount").unwrap();
+
+    let scan_result = if force_new.is_some_and(|f| f) {
+        manager
+            .force_new_scan(
+                &scan_id,
+                pattern,
+                redis_each_scan_count,
+                redis_each_scan_count,
+                None,
+                None,
+            )
+            .await?
+    } else {
+        manager
+            .scan(
+                &scan_id,
+                pattern,
+                redis_each_scan_count,
+                redis_each_scan_count,
+                None,
+                None,
+            )
+            .await?
+    };
+
+    let mut nodes =
+        RedisKeyNamespaceNode::fold(scan_result.keys(), prefix.as_bytes(), separator.as_bytes());
+    manager.accumulate_folder_counts(&scan_id, &mut nodes);
+
+    // Only the keys of the current level are typed, in one round trip.
+    let leaves: Vec<&mut RedisKeyNamespaceNode> = nodes
+        .iter_mut()
+        .filter(|node| node.kind == RedisKeyNamespaceNodeKind::Key)
+        .collect();
+    if !leaves.is_empty() {
+        let full_names: Vec<RedisBytes> =
+            leaves.iter().map(|leaf| leaf.full_name.clone()).collect();
+        let key_types = manager
+            .conn()?
+            .key_types(&full_names)
+            .await
+            .map_err(Error::RedisInternalError)?;
+        for (leaf, key_type) in leaves.into_iter().zip(key_types) {
+            leaf.key_type = Some(RedisKeyType::from(key_type));
+        }
+    }
+
+    Ok(Response::success(
+        Some(RedisKeyNamespacePage {
+            nodes,
+            can_continue: scan_result.can_continue(),
+        }),
+        None,
+    ))
+}
+
 #[tauri::command]
 pub async fn create_new_key(
     redis_client_manager: State<'_, RedisClientManagerState>,
     guid: Guid,
-    key_name: String,
+    key_name: RedisBytes,
     key_type: RedisKeyType,
 ) -> Result<Response<()>> {
     if !key_type.is_valid() {
@@ -224,7 +484,7 @@ pub async fn create_new_key(
     let conn = lock
         .get_mut(&guid)
         .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
-        .conn()?;
+        .writable_conn()?;
 
     match key_type {
         RedisKeyType::String => {
@@ -237,6 +497,31 @@ pub async fn create_new_key(
                 .await
                 .map_err(Error::RedisInternalError)?;
         }
+        RedisKeyType::List => {
+            conn.rpush(key_name, "New item")
+                .await
+                .map_err(Error::RedisInternalError)?;
+        }
+        RedisKeyType::Set => {
+            conn.sadd(key_name, "New member")
+                .await
+                .map_err(Error::RedisInternalError)?;
+        }
+        RedisKeyType::ZSet => {
+            conn.zadd(key_name, None, None, false, false, (0.0, "New member"))
+                .await
+                .map_err(Error::RedisInternalError)?;
+        }
+        RedisKeyType::Json => {
+            conn.json_set(key_name, REDIS_JSON_ROOT_PATH, serde_json::json!({}), None)
+                .await
+                .map_err(Error::RedisInternalError)?;
+        }
+        RedisKeyType::Stream => {
+            conn.xadd(key_name, false, None::<()>, "*", ("New field", "New value"))
+                .await
+                .map_err(Error::RedisInternalError)?;
+        }
         _ => {}
     }
 
@@ -247,7 +532,7 @@ pub async fn create_new_key(
 pub async fn remove_key(
     redis_client_manager: State<'_, RedisClientManagerState>,
     guid: Guid,
-    key_name: String,
+    key_name: RedisBytes,
 ) -> Result<Response<()>> {
     if key_name.is_empty() {
         return Err(Error::InvalidRedisKeyName);
@@ -257,7 +542,7 @@ pub async fn remove_key(
     let conn = lock
         .get_mut(&guid)
         .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
-        .conn()?;
+        .writable_conn()?;
 
     conn.del(key_name)
         .await
@@ -270,7 +555,7 @@ pub async fn remove_key(
 pub async fn get_key_type(
     redis_client_manager: State<'_, RedisClientManagerState>,
     guid: Guid,
-    key_name: String,
+    key_name: RedisBytes,
 ) -> Result<Response<RedisKeyType>> {
     if key_name.is_empty() {
         return Err(Error::InvalidRedisKeyName);
@@ -283,7 +568,7 @@ pub async fn get_key_type(
         .conn()?;
 
     let key_type: String = conn
-        .custom(CustomCommand::new("TYPE", None, false), vec![key_name])
+        .key_type(key_name)
         .await
         .map_err(Error::RedisInternalError)?;
 
@@ -294,7 +579,7 @@ pub async fn get_key_type(
 pub async fn get_key_ttl(
     redis_client_manager: State<'_, RedisClientManagerState>,
     guid: Guid,
-    key_name: String,
+    key_name: RedisBytes,
 ) -> Result<Response<TTL>> {
     if key_name.is_empty() {
         return Err(Error::InvalidRedisKeyName);
@@ -318,7 +603,7 @@ pub async fn get_key_ttl(
 pub async fn set_key_ttl(
     redis_client_manager: State<'_, RedisClientManagerState>,
     guid: Guid,
-    key_name: String,
+    key_name: RedisBytes,
     ttl: TTL,
 ) -> Result<Response<()>> {
     if key_name.is_empty() {
@@ -329,7 +614,7 @@ pub async fn set_key_ttl(
     let conn = lock
         .get_mut(&guid)
         .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
-        .conn()?;
+        .writable_conn()?;
 
     conn.expire(key_name, ttl)
         .await
@@ -342,8 +627,8 @@ pub async fn set_key_ttl(
 pub async fn rename_key(
     redis_client_manager: State<'_, RedisClientManagerState>,
     guid: Guid,
-    key_name: String,
-    new_key_name: String,
+    key_name: RedisBytes,
+    new_key_name: RedisBytes,
 ) -> Result<Response<()>> {
     if key_name.is_empty() || new_key_name.is_empty() {
         return Err(Error::InvalidRedisKeyName);
@@ -353,7 +638,7 @@ pub async fn rename_key(
     let conn = lock
         .get_mut(&guid)
         .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
-        .conn()?;
+        .writable_conn()?;
 
     conn.rename(key_name, new_key_name)
         .await
@@ -366,8 +651,8 @@ pub async fn rename_key(
 pub async fn get_key_content_type_string(
     redis_client_manager: State<'_, RedisClientManagerState>,
     guid: Guid,
-    key_name: String,
-) -> Result<Response<String>> {
+    key_name: RedisBytes,
+) -> Result<Response<RedisBytes>> {
     if key_name.is_empty() {
         return Err(Error::InvalidRedisKeyName);
     }
@@ -378,20 +663,24 @@ pub async fn get_key_content_type_string(
         .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
         .conn()?;
 
-    let content: String = conn
+    // Keeps the bytes untouched, decoding to `String` fails with the binary ones.
+    let content: RedisValue = conn
         .get(key_name)
         .await
         .map_err(Error::RedisInternalError)?;
 
-    Ok(Response::success(Some(content), None))
+    Ok(Response::success(
+        Some(RedisBytes::from_value(content).unwrap_or_default()),
+        None,
+    ))
 }
 
 #[tauri::command]
 pub async fn set_key_content_type_string(
     redis_client_manager: State<'_, RedisClientManagerState>,
     guid: Guid,
-    key_name: String,
-    content: String,
+    key_name: RedisBytes,
+    content: RedisBytes,
 ) -> Result<Response<()>> {
     if key_name.is_empty() {
         return Err(Error::InvalidRedisKeyName);
@@ -401,7 +690,7 @@ pub async fn set_key_content_type_string(
     let conn = lock
         .get_mut(&guid)
         .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
-        .conn()?;
+        .writable_conn()?;
 
     conn.set(key_name, content, None, None, false)
         .await
@@ -414,8 +703,8 @@ pub async fn set_key_content_type_string(
 pub async fn get_key_content_type_hash(
     redis_client_manager: State<'_, RedisClientManagerState>,
     guid: Guid,
-    key_name: String,
-) -> Result<Response<HashMap<String, String>>> {
+    key_name: RedisBytes,
+) -> Result<Response<Vec<RedisHScanResultItem>>> {
     if key_name.is_empty() {
         return Err(Error::InvalidRedisKeyName);
     }
@@ -426,20 +715,21 @@ pub async fn get_key_content_type_hash(
         .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
         .conn()?;
 
-    let content: HashMap<String, String> = conn
+    let content: RedisValue = conn
         .hgetall(key_name)
         .await
         .map_err(Error::RedisInternalError)?;
+    let content = content.into_map().map_err(Error::RedisInternalError)?;
 
-    Ok(Response::success(Some(content), None))
+    Ok(Response::success(Some(calc_hscan_items(content)), None))
 }
 
 #[tauri::command]
 pub async fn remove_hash_key_field(
     redis_client_manager: State<'_, RedisClientManagerState>,
     guid: Guid,
-    key_name: String,
-    field_name: String,
+    key_name: RedisBytes,
+    field_name: RedisBytes,
 ) -> Result<Response<()>> {
     if key_name.is_empty() {
         return Err(Error::InvalidRedisKeyName);
@@ -453,7 +743,7 @@ pub async fn remove_hash_key_field(
     let conn = lock
         .get_mut(&guid)
         .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
-        .conn()?;
+        .writable_conn()?;
 
     conn.hdel(key_name, field_name)
         .await
@@ -462,24 +752,36 @@ pub async fn remove_hash_key_field(
     Ok(Response::default())
 }
 
+fn calc_hscan_items(map: RedisMap) -> Vec<RedisHScanResultItem> {
+    map.inner()
+        .into_iter()
+        .map(|(name, value)| RedisHScanResultItem {
+            name: RedisBytes::from(&name),
+            value: RedisBytes::from_value(value).unwrap_or_default(),
+        })
+        .collect()
+}
+
 #[tauri::command]
 pub async fn hscan_key_all_values(
     redis_client_manager: State<'_, RedisClientManagerState>,
     settings_manager: State<'_, SettingsManager>,
     guid: Guid,
-    key_name: String,
+    key_name: RedisBytes,
     condition_part: Option<String>,
     force_new: Option<bool>,
+    scan_id: Option<ScanId>,
 ) -> Result<Response<Vec<RedisHScanResultItem>>> {
+    let scan_id = scan_id.unwrap_or_else(|| RedisScannerKind::Hash.default_scan_id());
+
     let mut lock = redis_client_manager.lock().await;
 
     let manager = lock
         .get_mut(&guid)
         .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?;
 
-    let pattern = condition_part
-        .and_then(|part| if part.is_empty() { None } else { Some(part) })
-        .map_or_else(|| "*".to_string(), |part| "*".to_string() + &part + "*");
+    // Matches the fields containing the input literally, the same as the keys.
+    let pattern = RedisScanFilter::default().pattern(condition_part);
 
     let settings_lock = settings_manager.read().await;
     let redis_each_scan_count: u32 = settings_lock.get_de("redisEachScanCount").unwrap();
@@ -487,6 +789,7 @@ pub async fn hscan_key_all_values(
     let scan_result = if force_new.is_some_and(|f| f) {
         manager
             .force_new_hscan(
+                &scan_id,
                 key_name.into(),
                 pattern,
                 redis_each_scan_count,
@@ -496,6 +799,7 @@ pub async fn hscan_key_all_values(
     } else {
         manager
             .hscan(
+                &scan_id,
                 key_name.into(),
                 pattern,
                 redis_each_scan_count,
@@ -504,17 +808,10 @@ pub async fn hscan_key_all_values(
             .await?
     };
 
-    let map = scan_result.take_map().inner();
-
-    let mut result: Vec<RedisHScanResultItem> = Default::default();
-    map.into_iter().for_each(|(name, value)| {
-        result.push(RedisHScanResultItem {
-            name: name.as_str().unwrap_or_default().to_string(),
-            value: value.as_string().unwrap_or_default(),
-        });
-    });
-
-    Ok(Response::success(Some(result), None))
+    Ok(Response::success(
+        Some(calc_hscan_items(scan_result.take_map())),
+        None,
+    ))
 }
 
 #[tauri::command]
@@ -522,35 +819,1794 @@ pub async fn refresh_hscaned_key_all_values(
     redis_client_manager: State<'_, RedisClientManagerState>,
     settings_manager: State<'_, SettingsManager>,
     guid: Guid,
-    key_name: String,
+    key_name: RedisBytes,
     condition_part: Option<String>,
     offset: Option<u32>,
+    scan_id: Option<ScanId>,
 ) -> Result<Response<Vec<RedisHScanResultItem>>> {
+    let scan_id = scan_id.unwrap_or_else(|| RedisScannerKind::Hash.default_scan_id());
+
     let mut lock = redis_client_manager.lock().await;
 
     let manager = lock
         .get_mut(&guid)
         .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?;
 
-    let pattern = condition_part
-        .and_then(|part| if part.is_empty() { None } else { Some(part) })
-        .map_or_else(|| "*".to_string(), |part| "*".to_string() + &part + "*");
+    // Matches the fields containing the input literally, the same as the keys.
+    let pattern = RedisScanFilter::default().pattern(condition_part);
 
     let settings_lock = settings_manager.read().await;
     let redis_each_scan_count: u32 = settings_lock.get_de("redisEachScanCount").unwrap();
 
     let scan_result = manager
-        .refresh_hscan(key_name.into(), pattern, redis_each_scan_count, offset)
+        .refresh_hscan(
+            &scan_id,
+            key_name.into(),
+            pattern,
+            redis_each_scan_count,
+            offset,
+        )
         .await?;
 
-    let map = scan_result.take_map().inner();
-    let mut result: Vec<RedisHScanResultItem> = Default::default();
-    map.into_iter().for_each(|(name, value)| {
-        result.push(RedisHScanResultItem {
-            name: name.as_str().unwrap_or_default().to_string(),
-            value: value.as_string().unwrap_or_default(),
-        });
-    });
+    Ok(Response::success(
+        Some(calc_hscan_items(scan_result.take_map())),
+        None,
+    ))
+}
 
-    Ok(Response::success(Some(result), None))
+#[tauri::command]
+pub async fn get_key_content_type_list(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    start: i64,
+    count: i64,
+) -> Result<Response<RedisListPage>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if start < 0 || count <= 0 {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .conn()?;
+
+    let total: u64 = conn
+        .llen(key_name.clone())
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    let items: Vec<RedisValue> = conn
+        .lrange(key_name, start, start + count - 1)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(
+        Some(RedisListPage {
+            items: items
+                .into_iter()
+                .map(|item| RedisBytes::from_value(item).unwrap_or_default())
+                .collect(),
+            total,
+        }),
+        None,
+    ))
+}
+
+#[tauri::command]
+pub async fn push_list_key_items(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    items: Vec<RedisBytes>,
+    head: Option<bool>,
+) -> Result<Response<u64>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if items.is_empty() {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    // Appends to the tail by default.
+    let total: u64 = if head.is_some_and(|h| h) {
+        conn.lpush(key_name, items).await
+    } else {
+        conn.rpush(key_name, items).await
+    }
+    .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(total), None))
+}
+
+#[tauri::command]
+pub async fn set_list_key_item(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    index: i64,
+    value: RedisBytes,
+) -> Result<Response<()>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    let _: () = conn
+        .lset(key_name, index, value)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::default())
+}
+
+#[tauri::command]
+pub async fn remove_list_key_item(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    value: RedisBytes,
+    count: Option<i64>,
+) -> Result<Response<u64>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    // Same as LREM, 0 removes all the matched ones, negative ones count from the tail.
+    let removed: u64 = conn
+        .lrem(key_name, count.unwrap_or(1), value)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(removed), None))
+}
+
+#[tauri::command]
+pub async fn insert_list_key_item(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    pivot: RedisBytes,
+    value: RedisBytes,
+    before: Option<bool>,
+) -> Result<Response<i64>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    let location = if before.is_some_and(|b| b) {
+        ListLocation::Before
+    } else {
+        ListLocation::After
+    };
+
+    // -1 if the pivot was not found.
+    let total: i64 = conn
+        .linsert(key_name, location, pivot, value)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(total), None))
+}
+
+#[tauri::command]
+pub async fn sscan_key_all_members(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    settings_manager: State<'_, SettingsManager>,
+    guid: Guid,
+    key_name: RedisBytes,
+    condition_part: Option<String>,
+    force_new: Option<bool>,
+    scan_id: Option<ScanId>,
+) -> Result<Response<Vec<RedisBytes>>> {
+    let scan_id = scan_id.unwrap_or_else(|| RedisScannerKind::Set.default_scan_id());
+
+    let mut lock = redis_client_manager.lock().await;
+
+    let manager = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?;
+
+    // Matches the members containing the input literally, the same as the keys.
+    let pattern = RedisScanFilter::default().pattern(condition_part);
+
+    let settings_lock = settings_manager.read().await;
+    let redis_each_scan_count: u32 = settings_lock.get_de("redisEachScanCount").unwrap();
+
+    let scan_result = if force_new.is_some_and(|f| f) {
+        manager
+            .force_new_member_scan::<RedisBytes>(
+                &scan_id,
+                key_name.into(),
+                pattern,
+                redis_each_scan_count,
+                redis_each_scan_count,
+            )
+            .await?
+    } else {
+        manager
+            .member_scan::<RedisBytes>(
+                &scan_id,
+                key_name.into(),
+                pattern,
+                redis_each_scan_count,
+                redis_each_scan_count,
+            )
+            .await?
+    };
+
+    Ok(Response::success(Some(scan_result.take_members()), None))
+}
+
+#[tauri::command]
+pub async fn refresh_sscaned_key_all_members(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    settings_manager: State<'_, SettingsManager>,
+    guid: Guid,
+    key_name: RedisBytes,
+    condition_part: Option<String>,
+    offset: Option<u32>,
+    scan_id: Option<ScanId>,
+) -> Result<Response<Vec<RedisBytes>>> {
+    let scan_id = scan_id.unwrap_or_else(|| RedisScannerKind::Set.default_scan_id());
+
+    let mut lock = redis_client_manager.lock().await;
+
+    let manager = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?;
+
+    // Matches the members containing the input literally, the same as the keys.
+    let pattern = RedisScanFilter::default().pattern(condition_part);
+
+    let settings_lock = settings_manager.read().await;
+    let redis_each_scan_count: u32 = settings_lock.get_de("redisEachScanCount").unwrap();
+
+    let scan_result = manager
+        .refresh_member_scan::<RedisBytes>(
+            &scan_id,
+            key_name.into(),
+            pattern,
+            redis_each_scan_count,
+            offset,
+        )
+        .await?;
+
+    Ok(Response::success(Some(scan_result.take_members()), None))
+}
+
+#[tauri::command]
+pub async fn get_set_key_members_count(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+) -> Result<Response<u64>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .conn()?;
+
+    let total: u64 = conn
+        .scard(key_name)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(total), None))
+}
+
+#[tauri::command]
+pub async fn add_set_key_members(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    members: Vec<RedisBytes>,
+) -> Result<Response<u64>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if members.is_empty() {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    // The members already existed are not counted.
+    let added: u64 = conn
+        .sadd(key_name, members)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(added), None))
+}
+
+#[tauri::command]
+pub async fn remove_set_key_members(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    members: Vec<RedisBytes>,
+) -> Result<Response<u64>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if members.is_empty() {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    let removed: u64 = conn
+        .srem(key_name, members)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(removed), None))
+}
+
+#[tauri::command]
+pub async fn is_set_key_member(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    member: RedisBytes,
+) -> Result<Response<bool>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .conn()?;
+
+    let existed: bool = conn
+        .sismember(key_name, member)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(existed), None))
+}
+
+#[tauri::command]
+pub async fn move_set_key_member(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    destination: RedisBytes,
+    member: RedisBytes,
+) -> Result<Response<bool>> {
+    if key_name.is_empty() || destination.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    // False if the member was not in the source set.
+    let moved: bool = conn
+        .smove(key_name, destination, member)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(moved), None))
+}
+
+// Score bounds are written like ZRANGE BYSCORE, `(` makes the bound exclusive.
+fn calc_zset_score_bound(bound: String) -> Result<ZRange> {
+    let bound = bound.trim();
+
+    match bound {
+        "-inf" | "+inf" => return Ok(bound.into()),
+        "inf" => return Ok("+inf".into()),
+        _ => {}
+    }
+
+    let (kind, score) = match bound.strip_prefix('(') {
+        Some(score) => (ZRangeKind::Exclusive, score),
+        None => (ZRangeKind::Inclusive, bound),
+    };
+
+    Ok(ZRange {
+        kind,
+        range: ZRangeBound::Score(score.parse::<f64>().map_err(|_| Error::InvalidParameter)?),
+    })
+}
+
+fn calc_zset_members(members: Vec<(RedisValue, f64)>) -> Vec<RedisZSetMember> {
+    members
+        .into_iter()
+        .filter_map(|(member, score)| {
+            RedisBytes::from_value(member).map(|member| RedisZSetMember { member, score })
+        })
+        .collect()
+}
+
+#[tauri::command]
+pub async fn get_key_content_type_zset(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    start: i64,
+    count: i64,
+    rev: Option<bool>,
+) -> Result<Response<RedisZSetPage>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if start < 0 || count <= 0 {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .conn()?;
+
+    let total: u64 = conn
+        .zcard(key_name.clone())
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    // Ranks are counted from the highest score when reversed.
+    let items: Vec<(RedisValue, f64)> = conn
+        .zrange(
+            key_name,
+            start,
+            start + count - 1,
+            None,
+            rev.is_some_and(|r| r),
+            None,
+            true,
+        )
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(
+        Some(RedisZSetPage {
+            items: calc_zset_members(items),
+            total,
+        }),
+        None,
+    ))
+}
+
+#[tauri::command]
+pub async fn range_zset_key_by_score(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    range: RedisZSetRangeQuery,
+) -> Result<Response<Vec<RedisZSetMember>>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let RedisZSetRangeQuery {
+        min,
+        max,
+        offset,
+        count,
+        rev,
+    } = range;
+    let min = calc_zset_score_bound(min)?;
+    let max = calc_zset_score_bound(max)?;
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .conn()?;
+
+    // REV expects the bounds from the higher one.
+    let rev = rev.is_some_and(|r| r);
+    let (from, to) = if rev { (max, min) } else { (min, max) };
+    let limit = count.map(|count| (offset.unwrap_or_default(), count));
+
+    let items: Vec<(RedisValue, f64)> = conn
+        .zrange(key_name, from, to, Some(ZSort::ByScore), rev, limit, true)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(calc_zset_members(items)), None))
+}
+
+#[tauri::command]
+pub async fn range_zset_key_by_lex(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    range: RedisZSetRangeQuery,
+) -> Result<Response<Vec<RedisBytes>>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .conn()?;
+
+    let RedisZSetRangeQuery {
+        min,
+        max,
+        offset,
+        count,
+        rev,
+    } = range;
+
+    // Bounds without `[` or `(` are treated as inclusive, `-` and `+` are the infinite ones.
+    let rev = rev.is_some_and(|r| r);
+    let (from, to) = if rev { (max, min) } else { (min, max) };
+    let limit = count.map(|count| (offset.unwrap_or_default(), count));
+
+    let items: Vec<RedisValue> = conn
+        .zrange(key_name, from, to, Some(ZSort::ByLex), rev, limit, false)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(
+        Some(
+            items
+                .into_iter()
+                .filter_map(RedisBytes::from_value)
+                .collect(),
+        ),
+        None,
+    ))
+}
+
+#[tauri::command]
+pub async fn zscan_key_all_members(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    settings_manager: State<'_, SettingsManager>,
+    guid: Guid,
+    key_name: RedisBytes,
+    condition_part: Option<String>,
+    force_new: Option<bool>,
+    scan_id: Option<ScanId>,
+) -> Result<Response<Vec<RedisZSetMember>>> {
+    let scan_id = scan_id.unwrap_or_else(|| RedisScannerKind::ZSet.default_scan_id());
+
+    let mut lock = redis_client_manager.lock().await;
+
+    let manager = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?;
+
+    // Matches the members containing the input literally, the same as the keys.
+    let pattern = RedisScanFilter::default().pattern(condition_part);
+
+    let settings_lock = settings_manager.read().await;
+    let redis_each_scan_count: u32 = settings_lock.get_de("redisEachScanCount").unwrap();
+
+    let scan_result = if force_new.is_some_and(|f| f) {
+        manager
+            .force_new_member_scan::<RedisZSetMember>(
+                &scan_id,
+                key_name.into(),
+                pattern,
+                redis_each_scan_count,
+                redis_each_scan_count,
+            )
+            .await?
+    } else {
+        manager
+            .member_scan::<RedisZSetMember>(
+                &scan_id,
+                key_name.into(),
+                pattern,
+                redis_each_scan_count,
+                redis_each_scan_count,
+            )
+            .await?
+    };
+
+    Ok(Response::success(Some(scan_result.take_members()), None))
+}
+
+#[tauri::command]
+pub async fn refresh_zscaned_key_all_members(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    settings_manager: State<'_, SettingsManager>,
+    guid: Guid,
+    key_name: RedisBytes,
+    condition_part: Option<String>,
+    offset: Option<u32>,
+    scan_id: Option<ScanId>,
+) -> Result<Response<Vec<RedisZSetMember>>> {
+    let scan_id = scan_id.unwrap_or_else(|| RedisScannerKind::ZSet.default_scan_id());
+
+    let mut lock = redis_client_manager.lock().await;
+
+    let manager = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?;
+
+    // Matches the members containing the input literally, the same as the keys.
+    let pattern = RedisScanFilter::default().pattern(condition_part);
+
+    let settings_lock = settings_manager.read().await;
+    let redis_each_scan_count: u32 = settings_lock.get_de("redisEachScanCount").unwrap();
+
+    let scan_result = manager
+        .refresh_member_scan::<RedisZSetMember>(
+            &scan_id,
+            key_name.into(),
+            pattern,
+            redis_each_scan_count,
+            offset,
+        )
+        .await?;
+
+    Ok(Response::success(Some(scan_result.take_members()), None))
+}
+
+#[tauri::command]
+pub async fn add_zset_key_members(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    members: Vec<RedisZSetMember>,
+) -> Result<Response<u64>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if members.is_empty() {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    let values: Vec<(f64, RedisBytes)> = members
+        .into_iter()
+        .map(|RedisZSetMember { member, score }| (score, member))
+        .collect();
+
+    // The scores of the existed members are updated but not counted.
+    let added: u64 = conn
+        .zadd(key_name, None, None, false, false, values)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(added), None))
+}
+
+#[tauri::command]
+pub async fn incr_zset_key_member_score(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    member: RedisBytes,
+    increment: f64,
+) -> Result<Response<f64>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    let score: f64 = conn
+        .zincrby(key_name, increment, member)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(score), None))
+}
+
+#[tauri::command]
+pub async fn set_zset_key_member_score(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    member: RedisBytes,
+    score: f64,
+) -> Result<Response<bool>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    // XX keeps the member from being created, CH counts the updated one.
+    let changed: u64 = conn
+        .zadd(
+            key_name,
+            Some(SetOptions::XX),
+            None,
+            true,
+            false,
+            (score, member),
+        )
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(changed > 0), None))
+}
+
+#[tauri::command]
+pub async fn remove_zset_key_members(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    members: Vec<RedisBytes>,
+) -> Result<Response<u64>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if members.is_empty() {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    let removed: u64 = conn
+        .zrem(key_name, members)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(removed), None))
+}
+
+#[tauri::command]
+pub async fn get_key_content_type_stream(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    start: Option<String>,
+    end: Option<String>,
+    count: u64,
+    rev: Option<bool>,
+) -> Result<Response<RedisStreamPage>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if count == 0 {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .conn()?;
+
+    let total: u64 = conn
+        .xlen(key_name.clone())
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    // Pages by passing the last id with `(` as the next exclusive bound.
+    let start = start.unwrap_or_else(|| "-".to_string());
+    let end = end.unwrap_or_else(|| "+".to_string());
+    let entries: RedisValue = if rev.is_some_and(|r| r) {
+        conn.xrevrange(key_name, end, start, Some(count)).await
+    } else {
+        conn.xrange(key_name, start, end, Some(count)).await
+    }
+    .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(
+        Some(RedisStreamPage {
+            entries: RedisStreamEntry::from_values(entries)?,
+            total,
+        }),
+        None,
+    ))
+}
+
+#[tauri::command]
+pub async fn add_stream_key_entry(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    id: Option<String>,
+    fields: Vec<RedisStreamField>,
+) -> Result<Response<String>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if fields.is_empty() {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    let fields: Vec<(RedisBytes, RedisBytes)> = fields
+        .into_iter()
+        .map(|RedisStreamField { name, value }| (name, value))
+        .collect();
+
+    // The id is generated by the server by default.
+    let id: String = conn
+        .xadd(
+            key_name,
+            false,
+            None::<()>,
+            id.unwrap_or_else(|| "*".to_string()),
+            fields,
+        )
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(id), None))
+}
+
+#[tauri::command]
+pub async fn remove_stream_key_entries(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    ids: Vec<String>,
+) -> Result<Response<u64>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if ids.is_empty() {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    let removed: u64 = conn
+        .xdel(key_name, ids)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(removed), None))
+}
+
+#[tauri::command]
+pub async fn get_stream_key_groups(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+) -> Result<Response<Vec<RedisStreamGroupInfo>>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .conn()?;
+
+    let groups: RedisValue = conn
+        .xinfo_groups(key_name)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    let groups = groups
+        .into_array()
+        .into_iter()
+        .map(RedisStreamGroupInfo::try_from)
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Response::success(Some(groups), None))
+}
+
+#[tauri::command]
+pub async fn get_stream_key_group_consumers(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    group: String,
+) -> Result<Response<Vec<RedisStreamConsumerInfo>>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .conn()?;
+
+    let consumers: RedisValue = conn
+        .xinfo_consumers(key_name, group)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    let consumers = consumers
+        .into_array()
+        .into_iter()
+        .map(RedisStreamConsumerInfo::try_from)
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Response::success(Some(consumers), None))
+}
+
+#[tauri::command]
+pub async fn get_stream_key_pending_summary(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    group: String,
+) -> Result<Response<RedisStreamPendingSummary>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .conn()?;
+
+    let summary: RedisValue = conn
+        .xpending(key_name, group, ())
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(
+        Some(RedisStreamPendingSummary::try_from(summary)?),
+        None,
+    ))
+}
+
+#[tauri::command]
+pub async fn get_stream_key_pending_entries(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    group: String,
+    query: RedisStreamPendingQuery,
+) -> Result<Response<Vec<RedisStreamPendingEntry>>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if query.count == 0 {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .conn()?;
+
+    let args = XPendingArgs {
+        idle: query.min_idle_time,
+        start: Some(query.start.unwrap_or_else(|| "-".to_string()).into()),
+        end: Some(query.end.unwrap_or_else(|| "+".to_string()).into()),
+        count: Some(query.count),
+        consumer: query.consumer.map(|consumer| consumer.into()),
+    };
+
+    let entries: RedisValue = conn
+        .xpending(key_name, group, args)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    let entries = entries
+        .into_array()
+        .into_iter()
+        .map(RedisStreamPendingEntry::try_from)
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Response::success(Some(entries), None))
+}
+
+#[tauri::command]
+pub async fn create_stream_key_group(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    group: String,
+    id: Option<String>,
+    mkstream: Option<bool>,
+) -> Result<Response<()>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if group.is_empty() {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    // Only delivers the new entries by default.
+    let _: () = conn
+        .xgroup_create(
+            key_name,
+            group,
+            id.unwrap_or_else(|| "$".to_string()),
+            mkstream.is_some_and(|m| m),
+        )
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::default())
+}
+
+#[tauri::command]
+pub async fn destroy_stream_key_group(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    group: String,
+) -> Result<Response<bool>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    let destroyed: bool = conn
+        .xgroup_destroy(key_name, group)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(destroyed), None))
+}
+
+#[tauri::command]
+pub async fn set_stream_key_group_id(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    group: String,
+    id: String,
+) -> Result<Response<()>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if id.is_empty() {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    let _: () = conn
+        .xgroup_setid(key_name, group, id)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::default())
+}
+
+#[tauri::command]
+pub async fn ack_stream_key_entries(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    group: String,
+    ids: Vec<String>,
+) -> Result<Response<u64>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if ids.is_empty() {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    let acked: u64 = conn
+        .xack(key_name, group, ids)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(acked), None))
+}
+
+#[tauri::command]
+pub async fn claim_stream_key_entries(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    group: String,
+    consumer: String,
+    min_idle_time: u64,
+    ids: Vec<String>,
+) -> Result<Response<Vec<RedisStreamEntry>>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if consumer.is_empty() || ids.is_empty() {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    // Only the entries idle longer than `min_idle_time` are claimed.
+    let entries: RedisValue = conn
+        .xclaim(
+            key_name,
+            group,
+            consumer,
+            min_idle_time,
+            ids,
+            None,
+            None,
+            None,
+            false,
+            false,
+        )
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(
+        Some(RedisStreamEntry::from_values(entries)?),
+        None,
+    ))
+}
+
+#[tauri::command]
+pub async fn auto_claim_stream_key_entries(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    group: String,
+    query: RedisStreamAutoClaimQuery,
+) -> Result<Response<RedisStreamAutoClaimResult>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if query.consumer.is_empty() {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    let result: RedisValue = conn
+        .xautoclaim(
+            key_name,
+            group,
+            query.consumer,
+            query.min_idle_time,
+            query.start.unwrap_or_else(|| "0-0".to_string()),
+            query.count,
+            false,
+        )
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(
+        Some(RedisStreamAutoClaimResult::try_from(result)?),
+        None,
+    ))
+}
+
+// The module is named `ReJSON` and both JSON.* commands and the `ReJSON-RL` type come with it.
+const REDIS_JSON_MODULE_NAME: &str = "rejson";
+const REDIS_JSON_ROOT_PATH: &str = "$";
+
+#[tauri::command]
+pub async fn is_json_module_loaded(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+) -> Result<Response<bool>> {
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .conn()?;
+
+    let modules: Vec<HashMap<String, RedisValue>> = conn
+        .module_list()
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    let loaded = modules.into_iter().any(|mut module| {
+        module
+            .remove("name")
+            .and_then(|name| name.as_string())
+            .is_some_and(|name| name.to_lowercase() == REDIS_JSON_MODULE_NAME)
+    });
+
+    Ok(Response::success(Some(loaded), None))
+}
+
+#[tauri::command]
+pub async fn get_key_content_type_json(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    path: Option<String>,
+) -> Result<Response<String>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .conn()?;
+
+    // JSONPath replies an array of the matched values.
+    let content: Option<String> = conn
+        .json_get(
+            key_name,
+            path.unwrap_or_else(|| REDIS_JSON_ROOT_PATH.to_string()),
+        )
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(content.unwrap_or_default()), None))
+}
+
+#[tauri::command]
+pub async fn set_json_key_value(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    path: Option<String>,
+    value: String,
+) -> Result<Response<()>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let value: serde_json::Value = serde_json::from_str(&value)?;
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    // Only the value on the path is replaced, the rest of the document is kept.
+    let _: RedisValue = conn
+        .json_set(
+            key_name,
+            path.unwrap_or_else(|| REDIS_JSON_ROOT_PATH.to_string()),
+            value,
+            None,
+        )
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::default())
+}
+
+#[tauri::command]
+pub async fn remove_json_key_path(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    path: String,
+) -> Result<Response<u64>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if path.is_empty() {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    // The whole key is removed with the root path.
+    let removed: u64 = conn
+        .json_del(key_name, path)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(Some(removed), None))
+}
+
+#[tauri::command]
+pub async fn append_json_key_array(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    path: String,
+    values: Vec<String>,
+) -> Result<Response<Vec<Option<u64>>>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    if path.is_empty() || values.is_empty() {
+        return Err(Error::InvalidParameter);
+    }
+
+    let values = values
+        .iter()
+        .map(|value| serde_json::from_str::<serde_json::Value>(value))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    // The new length of each matched array, nil if the matched one is not an array.
+    let lengths: RedisValue = conn
+        .json_arrappend(key_name, path, values)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::success(
+        Some(
+            lengths
+                .into_array()
+                .into_iter()
+                .map(|length| length.as_u64())
+                .collect(),
+        ),
+        None,
+    ))
+}
+
+#[tauri::command]
+pub async fn get_json_key_type(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    path: Option<String>,
+) -> Result<Response<Vec<String>>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .conn()?;
+
+    // One type for each matched value, RESP3 nests them once more.
+    let types: RedisValue = conn
+        .json_type(
+            key_name,
+            Some(path.unwrap_or_else(|| REDIS_JSON_ROOT_PATH.to_string())),
+        )
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    let types = types
+        .into_array()
+        .into_iter()
+        .flat_map(|value| value.into_array())
+        .filter_map(|value| value.as_string())
+        .collect();
+
+    Ok(Response::success(Some(types), None))
+}
+
+#[tauri::command]
+pub async fn list_value_formatters(
+    formatter_registry: State<'_, FormatterRegistryState>,
+) -> Result<Response<ValueFormatters>> {
+    let mut compressions = vec![NO_COMPRESSION.to_string()];
+    compressions.append(&mut formatter_registry.compressors());
+
+    Ok(Response::success(
+        Some(ValueFormatters {
+            formats: formatter_registry.formatters(),
+            compressions,
+        }),
+        None,
+    ))
+}
+
+#[tauri::command]
+pub async fn get_key_content_type_string_formatted(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    formatter_registry: State<'_, FormatterRegistryState>,
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    options: Option<ValueFormatOptions>,
+) -> Result<Response<FormattedValue>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let schemas = invoke_get_protobuf_schemas(&file_cache_manager, &guid).await?;
+    let mut options = options.unwrap_or_default();
+    let registry = options.with_protobuf_schemas(
+        &formatter_registry,
+        schemas.as_ref(),
+        key_name.as_bytes(),
+    )?;
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .conn()?;
+
+    let content: RedisValue = conn
+        .get(key_name)
+        .await
+        .map_err(Error::RedisInternalError)?;
+    let content = RedisBytes::from_value(content).unwrap_or_default();
+
+    Ok(Response::success(
+        Some(options.decode(&registry, content.as_bytes())?),
+        None,
+    ))
+}
+
+#[tauri::command]
+pub async fn set_key_content_type_string_formatted(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    formatter_registry: State<'_, FormatterRegistryState>,
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    content: String,
+    mut options: ValueFormatOptions,
+) -> Result<Response<()>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let schemas = invoke_get_protobuf_schemas(&file_cache_manager, &guid).await?;
+    let registry = options.with_protobuf_schemas(
+        &formatter_registry,
+        schemas.as_ref(),
+        key_name.as_bytes(),
+    )?;
+    let content = RedisBytes::from(options.encode(&registry, &content)?);
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    let _: () = conn
+        .set(key_name, content, None, None, false)
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::default())
+}
+
+#[tauri::command]
+pub async fn get_hash_key_field_formatted(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    formatter_registry: State<'_, FormatterRegistryState>,
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    field_name: RedisBytes,
+    options: Option<ValueFormatOptions>,
+) -> Result<Response<FormattedValue>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    // The fields share the message type mapped to the key.
+    let schemas = invoke_get_protobuf_schemas(&file_cache_manager, &guid).await?;
+    let mut options = options.unwrap_or_default();
+    let registry = options.with_protobuf_schemas(
+        &formatter_registry,
+        schemas.as_ref(),
+        key_name.as_bytes(),
+    )?;
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .conn()?;
+
+    let value: RedisValue = conn
+        .hget(key_name, field_name)
+        .await
+        .map_err(Error::RedisInternalError)?;
+    let value = RedisBytes::from_value(value).unwrap_or_default();
+
+    Ok(Response::success(
+        Some(options.decode(&registry, value.as_bytes())?),
+        None,
+    ))
+}
+
+#[tauri::command]
+pub async fn set_hash_key_field_formatted(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    formatter_registry: State<'_, FormatterRegistryState>,
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    guid: Guid,
+    key_name: RedisBytes,
+    field: RedisHashFormattedField,
+    mut options: ValueFormatOptions,
+) -> Result<Response<()>> {
+    if key_name.is_empty() {
+        return Err(Error::InvalidRedisKeyName);
+    }
+
+    let schemas = invoke_get_protobuf_schemas(&file_cache_manager, &guid).await?;
+    let registry = options.with_protobuf_schemas(
+        &formatter_registry,
+        schemas.as_ref(),
+        key_name.as_bytes(),
+    )?;
+    let value = RedisBytes::from(options.encode(&registry, &field.content)?);
+
+    let mut lock = redis_client_manager.lock().await;
+    let conn = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?
+        .writable_conn()?;
+
+    let _: RedisValue = conn
+        .hset(key_name, (field.name, value))
+        .await
+        .map_err(Error::RedisInternalError)?;
+
+    Ok(Response::default())
+}
+
+fn calc_scan_page(page: RedisScannerPage) -> RedisScanPage {
+    match page {
+        RedisScannerPage::Keys(result) => RedisScanPage {
+            kind: RedisScannerKind::Keys,
+            can_continue: result.can_continue(),
+            items: RedisScanItems::Keys(result.keys().iter().map(RedisBytes::from).collect()),
+        },
+        RedisScannerPage::Hash(result) => RedisScanPage {
+            kind: RedisScannerKind::Hash,
+            can_continue: result.can_continue(),
+            items: RedisScanItems::Hash(calc_hscan_items(result.take_map())),
+        },
+        RedisScannerPage::Set(result) => RedisScanPage {
+            kind: RedisScannerKind::Set,
+            can_continue: result.can_continue(),
+            items: RedisScanItems::Set(result.take_members()),
+        },
+        RedisScannerPage::ZSet(result) => RedisScanPage {
+            kind: RedisScannerKind::ZSet,
+            can_continue: result.can_continue(),
+            items: RedisScanItems::ZSet(result.take_members()),
+        },
+    }
+}
+
+/// Replaces the scanner with the same id, and replies the first page.
+#[tauri::command]
+pub async fn create_scanner(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    settings_manager: State<'_, SettingsManager>,
+    guid: Guid,
+    scan_id: ScanId,
+    options: RedisScannerOptions,
+) -> Result<Response<RedisScanPage>> {
+    if scan_id.is_empty() {
+        return Err(Error::InvalidParameter);
+    }
+
+    let mut lock = redis_client_manager.lock().await;
+
+    let manager = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?;
+
+    // The pattern is used as a glob without a filter.
+    let pattern = match &options.filter {
+        Some(filter) => filter.pattern(options.pattern),
+        None => options
+            .pattern
+            .filter(|pattern| !pattern.is_empty())
+            .unwrap_or_else(|| "*".to_string()),
+    };
+    let filter = options.filter.unwrap_or_default();
+    let r#type = filter.scan_type()?;
+    let regex = filter.regex()?;
+    // Only the keys are filtered by the type or the regex.
+    if options.kind != RedisScannerKind::Keys && (r#type.is_some() || regex.is_some()) {
+        return Err(Error::InvalidParameter);
+    }
+    let key_name = options.key_name.filter(|key_name| !key_name.is_empty());
+
+    let settings_lock = settings_manager.read().await;
+    let redis_each_scan_count: u32 = settings_lock.get_de("redisEachScanCount").unwrap();
+
+    let page = match options.kind {
+        RedisScannerKind::Keys => RedisScannerPage::Keys(
+            manager
+                .force_new_scan(
+                    &scan_id,
+                    pattern,
+                    redis_each_scan_count,
+                    redis_each_scan_count,
+                    r#type,
+                    regex,
+                )
+                .await?,
+        ),
+        RedisScannerKind::Hash => RedisScannerPage::Hash(
+            manager
+                .force_new_hscan(
+                    &scan_id,
+                    key_name.ok_or(Error::InvalidRedisKeyName)?.into(),
+                    pattern,
+                    redis_each_scan_count,
+                    redis_each_scan_count,
+                )
+                .await?,
+        ),
+        RedisScannerKind::Set => RedisScannerPage::Set(
+            manager
+                .force_new_member_scan::<RedisBytes>(
+                    &scan_id,
+                    key_name.ok_or(Error::InvalidRedisKeyName)?.into(),
+                    pattern,
+                    redis_each_scan_count,
+                    redis_each_scan_count,
+                )
+                .await?,
+        ),
+        RedisScannerKind::ZSet => RedisScannerPage::ZSet(
+            manager
+                .force_new_member_scan::<RedisZSetMember>(
+                    &scan_id,
+                    key_name.ok_or(Error::InvalidRedisKeyName)?.into(),
+                    pattern,
+                    redis_each_scan_count,
+                    redis_each_scan_count,
+                )
+                .await?,
+        ),
+    };
+
+    Ok(Response::success(Some(calc_scan_page(page)), None))
+}
+
+#[tauri::command]
+pub async fn continue_scanner(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    scan_id: ScanId,
+) -> Result<Response<RedisScanPage>> {
+    let mut lock = redis_client_manager.lock().await;
+
+    let manager = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?;
+
+    let page = manager.continue_scan(&scan_id).await?;
+
+    Ok(Response::success(Some(calc_scan_page(page)), None))
+}
+
+/// Replies whether the scanner existed, it may have been closed as idle.
+#[tauri::command]
+pub async fn close_scanner(
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: Guid,
+    scan_id: ScanId,
+) -> Result<Response<bool>> {
+    let mut lock = redis_client_manager.lock().await;
+
+    let manager = lock
+        .get_mut(&guid)
+        .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?;
+
+    Ok(Response::success(
+        Some(manager.close_scanner(&scan_id)),
+        None,
+    ))
+}
+
+/// Works with the default scanners too, whose scan ids are the kinds.
+#[tauri::command]
+pub async fn cancel_scan(guid: Guid, scan_id: ScanId) -> Result<Response<bool>> {
+    // Not locking the client manager, which is held by the running scan.
+    Ok(Response::success(
+        Some(RedisClientManager::cancel_scan(&guid, &scan_id)),
+        None,
+    ))
 }
@@ -1,29 +1,93 @@
 use crate::features::cache::FileCacheManagerState;
 use crate::features::client::{
-    RedisClientConnectionPayload, RedisClientManager, RedisClientManagerState,
+    RedisClient, RedisClientConnectionPayload, RedisClientManager, RedisClientManagerState,
+    RedisInfoDict,
+};
+use crate::features::command::{
+    ConnectionGroup, ConnectionInfo, ConnectionKind, ConnectionSecrets, ConnectionTestResult,
+    ConnectionsBundle, ConnectionsStore, Guid, ImportConnectionsResult, ProtobufMessageMapping,
+    ProtobufSchemaFile, ProtobufSchemas, SaveConnectionGroupPayload, SaveConnectionPayload,
 };
-use crate::features::command::{ConnectionInfo, Guid, SaveConnectionPayload};
 use crate::features::error::{Error, Result};
+use crate::features::events::{Events, RedisConnectionState};
+use crate::features::readonly::RedisReadonlyConn;
 use crate::features::response::Response;
+use crate::features::secrets::SecretsVaultState;
 use crate::utils::calculator::{gen_uuid, get_cur_time};
-use crate::utils::config::get_connections_file_cache_manager_key;
+use crate::utils::config::{
+    get_connections_file_cache_manager_key, get_protobuf_schemas_file_cache_manager_key,
+    get_redis_connection_timeout,
+};
 use crate::utils::judgements::judge_guid_valid;
-use tauri::State;
+use fred::error::RedisErrorKind;
+use fred::types::InfoKind;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Runtime, State};
 use tauri_redis_core::cache::abstracts::FileCacheBase;
+use tauri_redis_core::tunnel::ssh::{
+    SshHostKeyMismatch, SshTunnel, SshTunnelAuth, SshTunnelConfig,
+};
+use tauri_redis_core::vault::secrets::Secret;
 
-async fn invoke_get_connections(
+const CONNECTIONS_BUNDLE_VERSION: u32 = 1;
+
+async fn invoke_get_connections_store(
     file_cache_manager: &State<'_, FileCacheManagerState>,
-) -> Result<Vec<ConnectionInfo>> {
+) -> Result<ConnectionsStore> {
     let lock = file_cache_manager.lock().await;
     let connections_file_cache = lock
         .get(&get_connections_file_cache_manager_key()?)
         .ok_or_else(|| Error::FailedToGetCachedConnectionsInfo)?;
 
     connections_file_cache
-        .as_de::<Vec<ConnectionInfo>>()
+        .as_de::<ConnectionsStore>()
         .map_err(|_| Error::FailedToParseCachedConnectionsInfo)
 }
 
+async fn invoke_get_connections(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+) -> Result<Vec<ConnectionInfo>> {
+    invoke_get_connections_store(file_cache_manager)
+        .await
+        .map(|connections_store| connections_store.connections)
+}
+
+// Finds, updates and saves within a single lock, so the concurrent updates won't overwrite each other.
+// Nothing is saved if the update fails.
+async fn invoke_update_connections_store<F, T>(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+    update: F,
+) -> Result<T>
+where
+    F: FnOnce(&mut ConnectionsStore) -> Result<T>,
+{
+    let mut lock = file_cache_manager.lock().await;
+    let connections_file_cache = lock
+        .get_mut(&get_connections_file_cache_manager_key()?)
+        .ok_or_else(|| Error::FailedToGetCachedConnectionsInfo)?;
+
+    // Nothing saved yet.
+    let mut connections_store = match connections_file_cache.as_inner() {
+        Some(_) => connections_file_cache
+            .as_de::<ConnectionsStore>()
+            .map_err(|_| Error::FailedToParseCachedConnectionsInfo)?,
+        None => ConnectionsStore::default(),
+    };
+    let updated = update(&mut connections_store)?;
+
+    connections_file_cache
+        .replace_se(connections_store)
+        .map_err(|_| Error::FailedToSaveConnectionInfo)?;
+
+    connections_file_cache
+        .save(true)
+        .map_err(|_| Error::FailedToSaveConnectionInfo)?;
+
+    drop(lock);
+
+    Ok(updated)
+}
+
 async fn invoke_find_connection<'a>(
     connections_info: &'a Vec<ConnectionInfo>,
     guid: &Guid,
@@ -38,14 +102,139 @@ async fn invoke_find_connection<'a>(
         .and_then(|(idx, found_info)| Ok((idx, found_info)))
 }
 
-async fn invoke_establish_connection<'a>(
+// The same as `invoke_find_connection`, but usable within `invoke_update_connections_store`.
+fn invoke_find_connection_idx(connections_info: &[ConnectionInfo], guid: &Guid) -> Result<usize> {
+    judge_guid_valid(guid)?;
+
+    connections_info
+        .iter()
+        .position(|connection_info| connection_info.guid == *guid)
+        .ok_or_else(|| Error::FailedToFindTheMatchedConnectionInfo)
+}
+
+fn calc_ssh_tunnel_config(connection_info: &ConnectionInfo) -> SshTunnelConfig {
+    let ssh = &connection_info.ssh;
+    let auth = match ssh
+        .private_key_file
+        .as_ref()
+        .filter(|path| !path.is_empty())
+    {
+        Some(path) => SshTunnelAuth::PrivateKey {
+            path: path.clone(),
+            passphrase: ssh
+                .passphrase
+                .as_ref()
+                .and_then(Secret::plain)
+                .filter(|ps| !ps.is_empty())
+                .map(str::to_string),
+        },
+        None => SshTunnelAuth::Password(
+            ssh.password
+                .as_ref()
+                .and_then(Secret::plain)
+                .unwrap_or_default()
+                .to_string(),
+        ),
+    };
+
+    SshTunnelConfig {
+        host: ssh.host.clone(),
+        port: ssh.port,
+        username: ssh.username.clone(),
+        auth,
+        remote_host: connection_info.host.clone(),
+        remote_port: connection_info.port,
+        host_key_fingerprint: ssh.host_key_fingerprint.clone(),
+    }
+}
+
+async fn invoke_open_ssh_tunnel(connection_info: &ConnectionInfo) -> Result<SshTunnel> {
+    let timeout = Duration::from_secs(get_redis_connection_timeout()?.into());
+
+    tokio::time::timeout(
+        timeout,
+        SshTunnel::open(calc_ssh_tunnel_config(connection_info)),
+    )
+    .await
+    .map_err(|_| Error::FailedToOpenSshTunnel)?
+    .map_err(|err| match err.downcast_ref::<SshHostKeyMismatch>() {
+        Some(_) => Error::SshHostKeyMismatch,
+        None => Error::AnyhowError(err),
+    })
+}
+
+// Connects through the local end of the tunnel when ssh is enabled.
+// The tunnel must outlive the client built from the payload.
+async fn calc_redis_client_payload(
+    connection_info: &ConnectionInfo,
+    guid: &Guid,
+) -> Result<(RedisClientConnectionPayload, Option<SshTunnel>)> {
+    let mut payload = RedisClientConnectionPayload {
+        host: connection_info.host.clone(),
+        port: connection_info.port,
+        username: Some(connection_info.username.clone()),
+        password: connection_info
+            .password
+            .as_ref()
+            .and_then(Secret::plain)
+            .map(str::to_string),
+        readonly: connection_info.readonly,
+        db: connection_info.db,
+        kind: connection_info.kind.clone(),
+        tls: Some(connection_info.tls.clone()),
+        reconnect: Some(connection_info.reconnect.clone()),
+        guid: guid.clone(),
+    };
+
+    let tunnel = if connection_info.ssh.enabled {
+        // The tunnel only forwards to a single address.
+        if !matches!(connection_info.kind, ConnectionKind::Standalone) {
+            return Err(Error::UnsupportedSshTunnelConnectionKind);
+        }
+
+        let tunnel = invoke_open_ssh_tunnel(connection_info).await?;
+        let local_addr = tunnel.local_addr();
+
+        payload.host = local_addr.ip().to_string();
+        payload.port = local_addr.port();
+
+        // Still verify the certificate against the real host.
+        if let Some(tls) = payload.tls.as_mut() {
+            if tls
+                .server_name
+                .as_ref()
+                .map_or(true, |name| name.is_empty())
+            {
+                tls.server_name = Some(connection_info.host.clone());
+            }
+        }
+
+        Some(tunnel)
+    } else {
+        None
+    };
+
+    Ok((payload, tunnel))
+}
+
+async fn invoke_establish_connection<'a, R>(
+    handle: &AppHandle<R>,
     file_cache_manager: &State<'_, FileCacheManagerState>,
     redis_client_manager: &State<'_, RedisClientManagerState>,
+    secrets_vault: &State<'_, SecretsVaultState>,
     guid: &'a Guid,
-) -> Result<()> {
+) -> Result<()>
+where
+    R: Runtime,
+{
     let connections_info = invoke_get_connections(&file_cache_manager).await?;
     let (_, connection_info) = invoke_find_connection(&connections_info, guid).await?;
 
+    // Only the in-memory copy holds the plaintext secrets.
+    let mut connection_info = connection_info.clone();
+    connection_info.open_secrets(secrets_vault)?;
+    let connection_info = &connection_info;
+
     // Release the lock quickly.
     let mut lock = redis_client_manager.lock().await;
     let existed_one = lock.get_mut(guid);
@@ -56,15 +245,60 @@ async fn invoke_establish_connection<'a>(
 
     drop(lock);
 
-    // Use invoke functionality.
-    let client = RedisClientManager::invoke_new_client(RedisClientConnectionPayload {
-        host: connection_info.host.clone(),
-        port: connection_info.port.clone(),
-        username: Some(connection_info.username.clone()),
-        password: Some(connection_info.password.clone()),
-        guid: guid.clone(),
-    })
-    .await?;
+    let _ = Events::emit_redis_connection_state(handle, guid, RedisConnectionState::Connecting);
+
+    let connected = async {
+        let (payload, tunnel) = calc_redis_client_payload(connection_info, guid).await?;
+
+        // Use invoke functionality.
+        // The tunnel is dropped and closed if we failed to connect.
+        let mut client = RedisClientManager::invoke_new_client(payload).await?;
+        let host_key_fingerprint = tunnel
+            .as_ref()
+            .map(|tunnel| tunnel.host_key_fingerprint().to_string());
+        if let Some(tunnel) = tunnel {
+            client.attach_tunnel(tunnel);
+        }
+
+        Ok((client, host_key_fingerprint))
+    }
+    .await;
+
+    let (mut client, host_key_fingerprint) = match connected {
+        Ok(connected) => connected,
+        Err(err) => {
+            let state = match &err {
+                Error::RedisInternalError(err) if *err.kind() == RedisErrorKind::Auth => {
+                    RedisConnectionState::AuthFailed
+                }
+                _ => RedisConnectionState::Disconnected,
+            };
+            let _ = Events::emit_redis_connection_state(handle, guid, state);
+
+            return Err(err);
+        }
+    };
+
+    let _ = Events::emit_redis_connection_state(handle, guid, RedisConnectionState::Connected);
+
+    // Trust on first use, the later connections must present the same key.
+    if let Some(fingerprint) = host_key_fingerprint {
+        if connection_info.ssh.host_key_fingerprint.is_none() {
+            invoke_trust_ssh_host_key(file_cache_manager, guid, fingerprint).await?;
+        }
+    }
+
+    let watch_handle = handle.clone();
+    let watch_guid = guid.clone();
+    client.watch_state(move |state| {
+        let _ = Events::emit_redis_connection_state(&watch_handle, &watch_guid, state);
+    });
+
+    let progress_handle = handle.clone();
+    let progress_guid = guid.clone();
+    client.report_scan_progress(move |progress| {
+        let _ = Events::emit_redis_scan_progress(&progress_handle, &progress_guid, progress);
+    });
 
     // Only after we got the new client, we call the lock.
     // Otherwise if we call lock when we are trying to get client, it will stuck. It cannot concurrently connect to other clients.
@@ -75,17 +309,69 @@ async fn invoke_establish_connection<'a>(
     Ok(())
 }
 
-async fn invoke_release_connection<'a>(
+pub(crate) async fn invoke_get_connection_info(
     file_cache_manager: &State<'_, FileCacheManagerState>,
-    redis_client_manager: &State<'_, RedisClientManagerState>,
-    guid: &'a Guid,
+    guid: &Guid,
+) -> Result<ConnectionInfo> {
+    let connections_info = invoke_get_connections(file_cache_manager).await?;
+    let (_, connection_info) = invoke_find_connection(&connections_info, guid).await?;
+
+    Ok(connection_info.clone())
+}
+
+pub(crate) async fn invoke_remember_connection_db(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+    guid: &Guid,
+    db: u8,
 ) -> Result<()> {
-    let mut connections_info = invoke_get_connections(&file_cache_manager).await?;
-    let found = {
-        let found = invoke_find_connection(&connections_info, guid).await?;
+    invoke_update_connections_store(file_cache_manager, |connections_store| {
+        let idx = invoke_find_connection_idx(&connections_store.connections, guid)?;
+        connections_store.connections[idx].db = db;
 
-        (found.0, found.1.clone())
-    };
+        Ok(())
+    })
+    .await
+}
+
+async fn invoke_trust_ssh_host_key(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+    guid: &Guid,
+    fingerprint: String,
+) -> Result<()> {
+    invoke_update_connections_store(file_cache_manager, |connections_store| {
+        let idx = invoke_find_connection_idx(&connections_store.connections, guid)?;
+        connections_store.connections[idx].ssh.host_key_fingerprint = Some(fingerprint);
+
+        Ok(())
+    })
+    .await
+}
+
+async fn invoke_get_connection_secrets(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+    secrets_vault: &State<'_, SecretsVaultState>,
+    guid: &Guid,
+) -> Result<ConnectionSecrets> {
+    let connections_info = invoke_get_connections(file_cache_manager).await?;
+    let (_, connection_info) = invoke_find_connection(&connections_info, guid).await?;
+
+    let mut connection_info = connection_info.clone();
+    connection_info.open_secrets(secrets_vault)?;
+
+    Ok(connection_info.secrets())
+}
+
+async fn invoke_release_connection<'a, R>(
+    handle: &AppHandle<R>,
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+    redis_client_manager: &State<'_, RedisClientManagerState>,
+    guid: &'a Guid,
+) -> Result<()>
+where
+    R: Runtime,
+{
+    // Fails before releasing an unknown one.
+    invoke_get_connection_info(file_cache_manager, guid).await?;
 
     let mut lock = redis_client_manager.lock().await;
 
@@ -94,119 +380,522 @@ async fn invoke_release_connection<'a>(
     lock.release_client(guid).await?;
     drop(lock);
 
-    let (idx, mut connection_info) = found;
-    connection_info.connected_at = Some(get_cur_time());
-    connections_info.remove(idx);
-    connections_info.insert(idx, connection_info);
+    let _ = Events::emit_redis_connection_state(handle, guid, RedisConnectionState::Disconnected);
 
-    let mut lock = file_cache_manager.lock().await;
-    let connections_file_cache = lock
-        .get_mut(&get_connections_file_cache_manager_key()?)
-        .ok_or_else(|| Error::FailedToGetCachedConnectionsInfo)?;
+    invoke_update_connections_store(file_cache_manager, |connections_store| {
+        let idx = invoke_find_connection_idx(&connections_store.connections, guid)?;
+        connections_store.connections[idx].connected_at = Some(get_cur_time());
 
-    connections_file_cache
-        .replace_se(connections_info)
-        .map_err(|_| Error::FailedToSaveConnectionInfo)?;
+        Ok(())
+    })
+    .await
+}
 
-    connections_file_cache
-        .save(true)
-        .map_err(|_| Error::FailedToSaveConnectionInfo)?;
+// Applies the payload fields over the saved ones.
+fn calc_merged_connection(
+    previous: &ConnectionInfo,
+    info: SaveConnectionPayload,
+) -> ConnectionInfo {
+    let mut found = previous.clone();
 
-    drop(lock);
+    found.connection_name = info.connection_name.unwrap_or(found.connection_name);
+    found.host = info.host;
+    found.port = info.port;
+    found.username = info.username.unwrap_or(found.username);
+    found.password = info.password.map(Secret::from);
+    found.separator = info.separator.unwrap_or(found.separator);
+    found.readonly = info.readonly.unwrap_or(found.readonly);
+    found.db = info.db.unwrap_or(found.db);
+    found.kind = info.kind.unwrap_or(found.kind);
+    found.tls = info.tls.unwrap_or(found.tls);
+    found.ssh = info.ssh.unwrap_or(found.ssh);
+    // The trusted host key is kept until the ssh server changes, or cleared by an empty one.
+    let same_ssh_server = (found.ssh.host.as_str(), found.ssh.port)
+        == (previous.ssh.host.as_str(), previous.ssh.port);
+    match found.ssh.host_key_fingerprint.as_deref() {
+        Some("") => found.ssh.host_key_fingerprint = None,
+        None if same_ssh_server => {
+            found.ssh.host_key_fingerprint = previous.ssh.host_key_fingerprint.clone();
+        }
+        _ => {}
+    }
+    found.reconnect = info.reconnect.unwrap_or(found.reconnect);
+    found.env = info.env.unwrap_or(found.env);
+    found.inherit_secrets(previous);
 
-    Ok(())
+    found
+}
+
+async fn invoke_test_connection(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+    secrets_vault: &State<'_, SecretsVaultState>,
+    info: SaveConnectionPayload,
+) -> Result<ConnectionTestResult> {
+    // Editing an existed one, the secrets left out are taken from the saved one.
+    let previous = match info.guid.as_ref() {
+        Some(guid) => {
+            let connections_info = invoke_get_connections(file_cache_manager).await?;
+            let (_, connection_info) = invoke_find_connection(&connections_info, guid).await?;
+
+            let mut connection_info = connection_info.clone();
+            connection_info.open_secrets(secrets_vault)?;
+            connection_info
+        }
+        None => ConnectionInfo::default(),
+    };
+    let connection_info = calc_merged_connection(&previous, info);
+
+    // A temporary guid, so it never collides with the pending or recorded ones.
+    let (payload, tunnel) = calc_redis_client_payload(&connection_info, &gen_uuid()).await?;
+
+    // AUTH is sent while connecting.
+    let mut client = RedisClient::new(payload).await?;
+    if let Some(tunnel) = tunnel {
+        client.attach_tunnel(tunnel);
+    }
+
+    // The db is selected while connecting.
+    let result = invoke_probe_client(client.conn()?).await;
+
+    client.close().await?;
+
+    result
+}
+
+async fn invoke_probe_client(client: RedisReadonlyConn<'_>) -> Result<ConnectionTestResult> {
+    let started_at = Instant::now();
+    let _: () = client.ping().await.map_err(Error::RedisInternalError)?;
+    let latency = started_at.elapsed().as_millis() as u64;
+
+    let info: String = client
+        .info(Some(InfoKind::Server))
+        .await
+        .map_err(Error::RedisInternalError)?;
+    let server_version = RedisInfoDict::new(info)
+        .find("redis_version")
+        .and_then(|version| version.as_string());
+
+    // ACL is not available before Redis 6.
+    let username: Option<String> = client.acl_whoami().await.ok();
+
+    Ok(ConnectionTestResult {
+        latency,
+        server_version,
+        username,
+    })
 }
 
 async fn invoke_save_connection(
     file_cache_manager: &State<'_, FileCacheManagerState>,
+    secrets_vault: &State<'_, SecretsVaultState>,
     info: SaveConnectionPayload,
 ) -> Result<()> {
-    let connections_info = invoke_get_connections(&file_cache_manager).await;
-    let mut connections_info = connections_info.unwrap_or_default();
-    if info.guid.is_some() {
-        let found = {
-            let found =
-                invoke_find_connection(&connections_info, info.guid.as_ref().unwrap()).await?;
-
-            (found.0, found.1.clone())
-        };
+    invoke_update_connections_store(file_cache_manager, |connections_store| {
+        let connections_info = &mut connections_store.connections;
+        if let Some(guid) = info.guid.as_ref() {
+            let idx = invoke_find_connection_idx(connections_info, guid)?;
 
-        connections_info.remove(found.0);
-        connections_info.insert(found.0, {
-            let info = info.clone();
-            let mut found = found.1;
-
-            found.connection_name = info.connection_name.unwrap_or(found.connection_name);
-            found.host = info.host;
-            found.port = info.port;
-            found.username = info.username.unwrap_or(found.username);
-            found.password = info.password.unwrap_or(found.password);
-            found.separator = info.separator.unwrap_or(found.separator);
-            found.readonly = info.readonly.unwrap_or(found.readonly);
-            found.updated_at = Some(get_cur_time());
-
-            found
-        });
-    } else {
-        connections_info.push(ConnectionInfo {
-            guid: gen_uuid(),
-            created_at: get_cur_time(),
-            connection_name: info.connection_name.unwrap_or_default(),
-            host: info.host,
-            port: info.port,
-            separator: info.separator.unwrap_or_default(),
-            username: info.username.unwrap_or_default(),
-            password: info.password.unwrap_or_default(),
-            readonly: info.readonly.unwrap_or_default(),
-            updated_at: None,
-            connected_at: None,
-        });
+            let mut merged = calc_merged_connection(&connections_info[idx], info);
+            merged.updated_at = Some(get_cur_time());
+            connections_info[idx] = merged;
+        } else {
+            connections_info.push(ConnectionInfo {
+                guid: gen_uuid(),
+                created_at: get_cur_time(),
+                connection_name: info.connection_name.unwrap_or_default(),
+                host: info.host,
+                port: info.port,
+                separator: info.separator.unwrap_or_default(),
+                username: info.username.unwrap_or_default(),
+                password: info.password.map(Secret::from),
+                readonly: info.readonly.unwrap_or_default(),
+                db: info.db.unwrap_or_default(),
+                kind: info.kind.unwrap_or_default(),
+                tls: info.tls.unwrap_or_default(),
+                ssh: info.ssh.unwrap_or_default(),
+                reconnect: info.reconnect.unwrap_or_default(),
+                env: info.env.unwrap_or_default(),
+                group: None,
+                updated_at: None,
+                connected_at: None,
+            });
+        }
+
+        for connection_info in connections_info.iter_mut() {
+            connection_info.seal_secrets(secrets_vault)?;
+        }
+
+        Ok(())
+    })
+    .await
+}
+
+async fn invoke_export_connections(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+    secrets_vault: &State<'_, SecretsVaultState>,
+    guids: &[Guid],
+    with_secrets: bool,
+) -> Result<String> {
+    let connections_info = invoke_get_connections(file_cache_manager).await?;
+
+    let mut connections = Vec::with_capacity(guids.len());
+    for guid in guids {
+        let (_, connection_info) = invoke_find_connection(&connections_info, guid).await?;
+        let mut connection_info = connection_info.clone();
+
+        // The sealed secrets can only be opened by the local key.
+        if with_secrets {
+            connection_info.open_secrets(secrets_vault)?;
+        } else {
+            connection_info.mask_secrets();
+        }
+
+        connection_info.connected_at = None;
+        connections.push(connection_info);
+    }
+
+    let bundle = ConnectionsBundle {
+        version: CONNECTIONS_BUNDLE_VERSION,
+        exported_at: get_cur_time(),
+        connections,
+    };
+
+    Ok(serde_json::to_string_pretty(&bundle)?)
+}
+
+async fn invoke_import_connections(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+    secrets_vault: &State<'_, SecretsVaultState>,
+    bundle: &str,
+) -> Result<ImportConnectionsResult> {
+    let bundle: ConnectionsBundle =
+        serde_json::from_str(bundle).map_err(|_| Error::FailedToParseConnectionsBundle)?;
+    if bundle.version > CONNECTIONS_BUNDLE_VERSION {
+        return Err(Error::UnsupportedConnectionsBundleVersion);
+    }
+
+    invoke_update_connections_store(file_cache_manager, |connections_store| {
+        let connections_info = &mut connections_store.connections;
+        let mut result = ImportConnectionsResult::default();
+
+        for mut connection_info in bundle.connections {
+            let existed = connections_info.iter().any(|existed| {
+                existed.host == connection_info.host
+                    && existed.port == connection_info.port
+                    && existed.connection_name == connection_info.connection_name
+            });
+            if existed {
+                result.skipped.push(connection_info.connection_name);
+                continue;
+            }
+
+            // Plaintext ones pass through, the ones sealed by another key fail.
+            connection_info.open_secrets(secrets_vault)?;
+            connection_info.seal_secrets(secrets_vault)?;
+
+            // The groups are not shared along.
+            connection_info.group = None;
+            connection_info.guid = gen_uuid();
+            connection_info.created_at = get_cur_time();
+            connection_info.updated_at = None;
+            connection_info.connected_at = None;
+
+            result.imported.push(connection_info.guid.clone());
+            connections_info.push(connection_info);
+        }
+
+        Ok(result)
+    })
+    .await
+}
+
+async fn invoke_get_connection_uri(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+    secrets_vault: &State<'_, SecretsVaultState>,
+    guid: &Guid,
+    with_secrets: bool,
+) -> Result<String> {
+    let connections_info = invoke_get_connections(file_cache_manager).await?;
+    let (_, connection_info) = invoke_find_connection(&connections_info, guid).await?;
+
+    let mut connection_info = connection_info.clone();
+    connection_info.open_secrets(secrets_vault)?;
+
+    connection_info.to_uri(with_secrets)
+}
+
+async fn invoke_get_all_protobuf_schemas(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+) -> Result<Vec<ProtobufSchemas>> {
+    let lock = file_cache_manager.lock().await;
+    let protobuf_schemas_file_cache = lock
+        .get(&get_protobuf_schemas_file_cache_manager_key()?)
+        .ok_or_else(|| Error::FailedToGetCachedProtobufSchemas)?;
+
+    // No schema registered yet.
+    if protobuf_schemas_file_cache.as_inner().is_none() {
+        return Ok(vec![]);
     }
 
+    protobuf_schemas_file_cache
+        .as_de::<Vec<ProtobufSchemas>>()
+        .map_err(|_| Error::FailedToParseCachedProtobufSchemas)
+}
+
+async fn invoke_replace_all_protobuf_schemas(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+    all_protobuf_schemas: Vec<ProtobufSchemas>,
+) -> Result<()> {
     let mut lock = file_cache_manager.lock().await;
-    let connections_file_cache = lock
-        .get_mut(&get_connections_file_cache_manager_key()?)
-        .ok_or_else(|| Error::FailedToGetCachedConnectionsInfo)?;
+    let protobuf_schemas_file_cache = lock
+        .get_mut(&get_protobuf_schemas_file_cache_manager_key()?)
+        .ok_or_else(|| Error::FailedToGetCachedProtobufSchemas)?;
 
-    connections_file_cache
-        .replace_se(connections_info)
-        .map_err(|_| Error::FailedToSaveConnectionInfo)?;
+    protobuf_schemas_file_cache
+        .replace_se(all_protobuf_schemas)
+        .map_err(|_| Error::FailedToSaveProtobufSchemas)?;
 
-    connections_file_cache
+    protobuf_schemas_file_cache
         .save(true)
-        .map_err(|_| Error::FailedToSaveConnectionInfo)?;
+        .map_err(|_| Error::FailedToSaveProtobufSchemas)?;
 
     drop(lock);
 
     Ok(())
 }
 
-async fn invoke_remove_connection<'a>(
+pub(crate) async fn invoke_get_protobuf_schemas(
     file_cache_manager: &State<'_, FileCacheManagerState>,
-    guid: &'a Guid,
+    guid: &Guid,
+) -> Result<Option<ProtobufSchemas>> {
+    Ok(invoke_get_all_protobuf_schemas(file_cache_manager)
+        .await?
+        .into_iter()
+        .find(|schemas| schemas.guid == *guid))
+}
+
+// Returns the message types, the schemas are compiled before saving.
+async fn invoke_save_protobuf_schemas(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+    guid: &Guid,
+    files: Vec<ProtobufSchemaFile>,
+    mappings: Vec<ProtobufMessageMapping>,
+) -> Result<Vec<String>> {
+    let connections_info = invoke_get_connections(file_cache_manager).await?;
+    invoke_find_connection(&connections_info, guid).await?;
+
+    if files.iter().any(|file| file.name.is_empty())
+        || mappings.iter().any(|mapping| mapping.pattern.is_empty())
+    {
+        return Err(Error::InvalidParameter);
+    }
+
+    let schemas = ProtobufSchemas {
+        files,
+        mappings,
+        guid: guid.clone(),
+    };
+
+    let compiled = schemas.compile()?;
+    for mapping in &schemas.mappings {
+        compiled.formatter(&mapping.message_type)?;
+    }
+
+    let mut all_protobuf_schemas = invoke_get_all_protobuf_schemas(file_cache_manager).await?;
+    all_protobuf_schemas.retain(|schemas| schemas.guid != *guid);
+    if !schemas.files.is_empty() || !schemas.mappings.is_empty() {
+        all_protobuf_schemas.push(schemas);
+    }
+
+    invoke_replace_all_protobuf_schemas(file_cache_manager, all_protobuf_schemas).await?;
+    ProtobufSchemas::invalidate_compiled(guid);
+
+    Ok(compiled.message_types())
+}
+
+async fn invoke_remove_protobuf_schemas(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+    guid: &Guid,
 ) -> Result<()> {
-    judge_guid_valid(guid)?;
+    ProtobufSchemas::invalidate_compiled(guid);
 
-    let mut connections_info = invoke_get_connections(&file_cache_manager)
-        .await
-        .unwrap_or_default();
-    let found = invoke_find_connection(&connections_info, guid).await?;
-    connections_info.remove(found.0);
+    let mut all_protobuf_schemas = invoke_get_all_protobuf_schemas(file_cache_manager).await?;
+    let len = all_protobuf_schemas.len();
+    all_protobuf_schemas.retain(|schemas| schemas.guid != *guid);
 
-    let mut lock = file_cache_manager.lock().await;
+    if all_protobuf_schemas.len() == len {
+        return Ok(());
+    }
+
+    invoke_replace_all_protobuf_schemas(file_cache_manager, all_protobuf_schemas).await
+}
+
+async fn invoke_get_connection_groups(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+) -> Result<Vec<ConnectionGroup>> {
+    let lock = file_cache_manager.lock().await;
     let connections_file_cache = lock
-        .get_mut(&get_connections_file_cache_manager_key()?)
+        .get(&get_connections_file_cache_manager_key()?)
         .ok_or_else(|| Error::FailedToGetCachedConnectionsInfo)?;
 
-    connections_file_cache
-        .replace_se(connections_info)
-        .map_err(|_| Error::FailedToSaveConnectionInfo)?;
+    // Nothing saved yet.
+    if connections_file_cache.as_inner().is_none() {
+        return Ok(vec![]);
+    }
 
     connections_file_cache
-        .save(true)
-        .map_err(|_| Error::FailedToSaveConnectionInfo)?;
+        .as_de::<ConnectionsStore>()
+        .map(|connections_store| connections_store.groups)
+        .map_err(|_| Error::FailedToParseCachedConnectionsInfo)
+}
 
-    drop(lock);
+fn invoke_find_connection_group<'a>(
+    connection_groups: &'a [ConnectionGroup],
+    guid: &Guid,
+) -> Result<(usize, &'a ConnectionGroup)> {
+    judge_guid_valid(guid)?;
+
+    connection_groups
+        .iter()
+        .enumerate()
+        .find(|(_, group)| group.guid == *guid)
+        .ok_or_else(|| Error::FailedToFindTheMatchedConnectionGroup)
+}
+
+async fn invoke_save_connection_group(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+    info: SaveConnectionGroupPayload,
+) -> Result<Guid> {
+    invoke_update_connections_store(file_cache_manager, |connections_store| {
+        let connection_groups = &mut connections_store.groups;
+
+        let guid = match info.guid {
+            Some(guid) => {
+                let (idx, _) = invoke_find_connection_group(connection_groups, &guid)?;
+                let found = &mut connection_groups[idx];
+
+                found.name = info.name;
+                found.color = info.color;
+                found.updated_at = Some(get_cur_time());
+
+                guid
+            }
+            None => {
+                let guid = gen_uuid();
+                connection_groups.push(ConnectionGroup {
+                    name: info.name,
+                    color: info.color,
+                    created_at: get_cur_time(),
+                    updated_at: None,
+                    guid: guid.clone(),
+                });
+
+                guid
+            }
+        };
+
+        Ok(guid)
+    })
+    .await
+}
+
+async fn invoke_remove_connection_group(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+    guid: &Guid,
+) -> Result<()> {
+    invoke_update_connections_store(file_cache_manager, |connections_store| {
+        invoke_find_connection_group(&connections_store.groups, guid)?;
+
+        connections_store
+            .groups
+            .retain(|connection_group| connection_group.guid != *guid);
+
+        // The connections are kept, only become ungrouped.
+        for connection_info in connections_store.connections.iter_mut() {
+            if connection_info.group.as_ref() == Some(guid) {
+                connection_info.group = None;
+            }
+        }
+
+        Ok(())
+    })
+    .await
+}
+
+async fn invoke_reorder_connection_groups(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+    guids: &[Guid],
+) -> Result<()> {
+    invoke_update_connections_store(file_cache_manager, |connections_store| {
+        let connection_groups = &mut connections_store.groups;
+
+        let mut reordered = Vec::with_capacity(connection_groups.len());
+        for guid in guids {
+            let (idx, _) = invoke_find_connection_group(connection_groups, guid)?;
+            reordered.push(connection_groups.remove(idx));
+        }
+
+        // The ones not mentioned keep their relative order at the end.
+        reordered.append(connection_groups);
+        *connection_groups = reordered;
+
+        Ok(())
+    })
+    .await
+}
+
+async fn invoke_move_connection(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+    guid: &Guid,
+    group: Option<Guid>,
+    index: Option<usize>,
+) -> Result<()> {
+    invoke_update_connections_store(file_cache_manager, |connections_store| {
+        if let Some(group) = group.as_ref() {
+            invoke_find_connection_group(&connections_store.groups, group)?;
+        }
+
+        let connections_info = &mut connections_store.connections;
+        let idx = invoke_find_connection_idx(connections_info, guid)?;
+        let mut connection_info = connections_info.remove(idx);
+        connection_info.group = group;
+
+        // The index is relative to the connections of the target group.
+        let siblings: Vec<usize> = connections_info
+            .iter()
+            .enumerate()
+            .filter(|(_, sibling)| sibling.group == connection_info.group)
+            .map(|(idx, _)| idx)
+            .collect();
+        let position = match index.and_then(|index| siblings.get(index)) {
+            Some(position) => *position,
+            None => siblings
+                .last()
+                .map_or(connections_info.len(), |position| position + 1),
+        };
+        connections_info.insert(position, connection_info);
+
+        Ok(())
+    })
+    .await
+}
+
+async fn invoke_remove_connection<'a>(
+    file_cache_manager: &State<'_, FileCacheManagerState>,
+    guid: &'a Guid,
+) -> Result<()> {
+    judge_guid_valid(guid)?;
+
+    invoke_update_connections_store(file_cache_manager, |connections_store| {
+        let idx = invoke_find_connection_idx(&connections_store.connections, guid)?;
+        connections_store.connections.remove(idx);
+
+        Ok(())
+    })
+    .await?;
+
+    // Not worth failing the removal.
+    let _ = invoke_remove_protobuf_schemas(file_cache_manager, guid).await;
 
     Ok(())
 }
@@ -214,6 +903,7 @@ async fn invoke_remove_connection<'a>(
 #[tauri::command]
 pub async fn save_connection(
     file_cache_manager: State<'_, FileCacheManagerState>,
+    secrets_vault: State<'_, SecretsVaultState>,
     connection_info: &str,
 ) -> Result<Response<()>> {
     let info: Result<SaveConnectionPayload> =
@@ -222,40 +912,91 @@ pub async fn save_connection(
         return Ok(info.err().unwrap().into());
     }
 
-    invoke_save_connection(&file_cache_manager, info.unwrap()).await?;
+    invoke_save_connection(&file_cache_manager, &secrets_vault, info.unwrap()).await?;
 
     Ok(Response::default())
 }
 
+#[tauri::command]
+pub async fn test_connection(
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    secrets_vault: State<'_, SecretsVaultState>,
+    connection_info: &str,
+) -> Result<Response<ConnectionTestResult>> {
+    let info: Result<SaveConnectionPayload> =
+        serde_json::from_str(connection_info).map_err(|_| Error::FailedToParseConnectionInfo);
+    if info.is_err() {
+        return Ok(info.err().unwrap().into());
+    }
+
+    invoke_test_connection(&file_cache_manager, &secrets_vault, info.unwrap())
+        .await
+        .map(|result| Response::success(Some(result), None))
+        .or_else(|err| Ok(err.into()))
+}
+
 #[tauri::command]
 pub async fn get_connections(
     file_cache_manager: State<'_, FileCacheManagerState>,
 ) -> Result<Response<Vec<ConnectionInfo>>> {
     invoke_get_connections(&file_cache_manager)
         .await
-        .and_then(|connections_info| Ok(Response::success(Some(connections_info), None)))
+        .and_then(|mut connections_info| {
+            connections_info
+                .iter_mut()
+                .for_each(|connection_info| connection_info.mask_secrets());
+
+            Ok(Response::success(Some(connections_info), None))
+        })
         .or_else(|err| Ok(err.into()))
 }
 
 #[tauri::command]
-pub async fn establish_connection(
+pub async fn get_connection_secrets(
     file_cache_manager: State<'_, FileCacheManagerState>,
-    redis_client_manager: State<'_, RedisClientManagerState>,
-    guid: String,
-) -> Result<Response<()>> {
-    invoke_establish_connection(&file_cache_manager, &redis_client_manager, &guid)
+    secrets_vault: State<'_, SecretsVaultState>,
+    guid: Guid,
+) -> Result<Response<ConnectionSecrets>> {
+    invoke_get_connection_secrets(&file_cache_manager, &secrets_vault, &guid)
         .await
-        .and_then(|_| Ok(Response::default()))
+        .map(|secrets| Response::success(Some(secrets), None))
         .or_else(|err| Ok(err.into()))
 }
 
 #[tauri::command]
-pub async fn release_connection(
+pub async fn establish_connection<R>(
+    handle: AppHandle<R>,
     file_cache_manager: State<'_, FileCacheManagerState>,
     redis_client_manager: State<'_, RedisClientManagerState>,
+    secrets_vault: State<'_, SecretsVaultState>,
     guid: String,
-) -> Result<Response<()>> {
-    invoke_release_connection(&file_cache_manager, &redis_client_manager, &guid)
+) -> Result<Response<()>>
+where
+    R: Runtime,
+{
+    invoke_establish_connection(
+        &handle,
+        &file_cache_manager,
+        &redis_client_manager,
+        &secrets_vault,
+        &guid,
+    )
+    .await
+    .and_then(|_| Ok(Response::default()))
+    .or_else(|err| Ok(err.into()))
+}
+
+#[tauri::command]
+pub async fn release_connection<R>(
+    handle: AppHandle<R>,
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    redis_client_manager: State<'_, RedisClientManagerState>,
+    guid: String,
+) -> Result<Response<()>>
+where
+    R: Runtime,
+{
+    invoke_release_connection(&handle, &file_cache_manager, &redis_client_manager, &guid)
         .await
         .and_then(|_| Ok(Response::default()))
         .or_else(|err| Ok(err.into()))
@@ -271,3 +1012,157 @@ pub async fn remove_connection(
         .and_then(|_| Ok(Response::default()))
         .or_else(|err| Ok(err.into()))
 }
+
+#[tauri::command]
+pub async fn export_connections(
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    secrets_vault: State<'_, SecretsVaultState>,
+    guids: Vec<Guid>,
+    with_secrets: bool,
+) -> Result<Response<String>> {
+    invoke_export_connections(&file_cache_manager, &secrets_vault, &guids, with_secrets)
+        .await
+        .map(|bundle| Response::success(Some(bundle), None))
+        .or_else(|err| Ok(err.into()))
+}
+
+#[tauri::command]
+pub async fn import_connections(
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    secrets_vault: State<'_, SecretsVaultState>,
+    bundle: &str,
+) -> Result<Response<ImportConnectionsResult>> {
+    invoke_import_connections(&file_cache_manager, &secrets_vault, bundle)
+        .await
+        .map(|result| Response::success(Some(result), None))
+        .or_else(|err| Ok(err.into()))
+}
+
+#[tauri::command]
+pub async fn parse_connection_uri(uri: &str) -> Result<Response<SaveConnectionPayload>> {
+    SaveConnectionPayload::from_uri(uri)
+        .map(|payload| Response::success(Some(payload), None))
+        .or_else(|err| Ok(err.into()))
+}
+
+#[tauri::command]
+pub async fn get_connection_uri(
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    secrets_vault: State<'_, SecretsVaultState>,
+    guid: Guid,
+    with_secrets: bool,
+) -> Result<Response<String>> {
+    invoke_get_connection_uri(&file_cache_manager, &secrets_vault, &guid, with_secrets)
+        .await
+        .map(|uri| Response::success(Some(uri), None))
+        .or_else(|err| Ok(err.into()))
+}
+
+#[tauri::command]
+pub async fn get_connection_groups(
+    file_cache_manager: State<'_, FileCacheManagerState>,
+) -> Result<Response<Vec<ConnectionGroup>>> {
+    invoke_get_connection_groups(&file_cache_manager)
+        .await
+        .map(|connection_groups| Response::success(Some(connection_groups), None))
+        .or_else(|err| Ok(err.into()))
+}
+
+#[tauri::command]
+pub async fn save_connection_group(
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    group_info: &str,
+) -> Result<Response<Guid>> {
+    let info: Result<SaveConnectionGroupPayload> =
+        serde_json::from_str(group_info).map_err(|_| Error::FailedToParseConnectionGroup);
+    if info.is_err() {
+        return Ok(info.err().unwrap().into());
+    }
+
+    invoke_save_connection_group(&file_cache_manager, info.unwrap())
+        .await
+        .map(|guid| Response::success(Some(guid), None))
+        .or_else(|err| Ok(err.into()))
+}
+
+#[tauri::command]
+pub async fn remove_connection_group(
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    guid: Guid,
+) -> Result<Response<()>> {
+    invoke_remove_connection_group(&file_cache_manager, &guid)
+        .await
+        .map(|_| Response::default())
+        .or_else(|err| Ok(err.into()))
+}
+
+#[tauri::command]
+pub async fn reorder_connection_groups(
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    guids: Vec<Guid>,
+) -> Result<Response<()>> {
+    invoke_reorder_connection_groups(&file_cache_manager, &guids)
+        .await
+        .map(|_| Response::default())
+        .or_else(|err| Ok(err.into()))
+}
+
+#[tauri::command]
+pub async fn move_connection(
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    guid: Guid,
+    group: Option<Guid>,
+    index: Option<usize>,
+) -> Result<Response<()>> {
+    invoke_move_connection(&file_cache_manager, &guid, group, index)
+        .await
+        .map(|_| Response::default())
+        .or_else(|err| Ok(err.into()))
+}
+
+#[tauri::command]
+pub async fn get_protobuf_schemas(
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    guid: Guid,
+) -> Result<Response<ProtobufSchemas>> {
+    invoke_get_protobuf_schemas(&file_cache_manager, &guid)
+        .await
+        .map(|schemas| {
+            Response::success(
+                Some(schemas.unwrap_or_else(|| ProtobufSchemas {
+                    guid: guid.clone(),
+                    ..Default::default()
+                })),
+                None,
+            )
+        })
+        .or_else(|err| Ok(err.into()))
+}
+
+#[tauri::command]
+pub async fn save_protobuf_schemas(
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    guid: Guid,
+    files: Vec<ProtobufSchemaFile>,
+    mappings: Vec<ProtobufMessageMapping>,
+) -> Result<Response<Vec<String>>> {
+    invoke_save_protobuf_schemas(&file_cache_manager, &guid, files, mappings)
+        .await
+        .map(|message_types| Response::success(Some(message_types), None))
+        .or_else(|err| Ok(err.into()))
+}
+
+#[tauri::command]
+pub async fn list_protobuf_message_types(
+    file_cache_manager: State<'_, FileCacheManagerState>,
+    guid: Guid,
+) -> Result<Response<Vec<String>>> {
+    invoke_get_protobuf_schemas(&file_cache_manager, &guid)
+        .await
+        .and_then(|schemas| match schemas {
+            Some(schemas) => Ok(schemas.compile()?.message_types()),
+            None => Ok(vec![]),
+        })
+        .map(|message_types| Response::success(Some(message_types), None))
+        .or_else(|err| Ok(err.into()))
+}
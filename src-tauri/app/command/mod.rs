@@ -14,14 +14,31 @@ where
         telemetry::record_page_route_visited,
         telemetry::judge_page_route_visited,
         connections::save_connection,
+        connections::test_connection,
         connections::get_connections,
+        connections::get_connection_secrets,
         connections::establish_connection,
         connections::release_connection,
         connections::remove_connection,
+        connections::export_connections,
+        connections::import_connections,
+        connections::parse_connection_uri,
+        connections::get_connection_uri,
+        connections::get_connection_groups,
+        connections::save_connection_group,
+        connections::remove_connection_group,
+        connections::reorder_connection_groups,
+        connections::move_connection,
+        connections::get_protobuf_schemas,
+        connections::save_protobuf_schemas,
+        connections::list_protobuf_message_types,
         client::db_nums,
         client::switch_db,
+        client::current_db,
+        client::keyspace_summary,
         client::flush_all,
         client::list_client_metrics,
+        client::get_master_address,
         client::list_all_keys,
         client::create_new_key,
         client::remove_key,
@@ -33,9 +50,59 @@ where
         client::rename_key,
         client::scan_all_keys,
         client::refresh_scanned_all_keys,
+        client::scan_key_namespace_tree,
+        client::create_scanner,
+        client::continue_scanner,
+        client::close_scanner,
+        client::cancel_scan,
         client::get_key_content_type_hash,
         client::hscan_key_all_values,
         client::refresh_hscaned_key_all_values,
         client::remove_hash_key_field,
+        client::get_key_content_type_list,
+        client::push_list_key_items,
+        client::set_list_key_item,
+        client::remove_list_key_item,
+        client::insert_list_key_item,
+        client::sscan_key_all_members,
+        client::refresh_sscaned_key_all_members,
+        client::get_set_key_members_count,
+        client::add_set_key_members,
+        client::remove_set_key_members,
+        client::is_set_key_member,
+        client::move_set_key_member,
+        client::get_key_content_type_zset,
+        client::range_zset_key_by_score,
+        client::range_zset_key_by_lex,
+        client::zscan_key_all_members,
+        client::refresh_zscaned_key_all_members,
+        client::add_zset_key_members,
+        client::incr_zset_key_member_score,
+        client::set_zset_key_member_score,
+        client::remove_zset_key_members,
+        client::get_key_content_type_stream,
+        client::add_stream_key_entry,
+        client::remove_stream_key_entries,
+        client::get_stream_key_groups,
+        client::get_stream_key_group_consumers,
+        client::get_stream_key_pending_summary,
+        client::get_stream_key_pending_entries,
+        client::create_stream_key_group,
+        client::destroy_stream_key_group,
+        client::set_stream_key_group_id,
+        client::ack_stream_key_entries,
+        client::claim_stream_key_entries,
+        client::auto_claim_stream_key_entries,
+        client::is_json_module_loaded,
+        client::get_key_content_type_json,
+        client::set_json_key_value,
+        client::remove_json_key_path,
+        client::append_json_key_array,
+        client::get_json_key_type,
+        client::list_value_formatters,
+        client::get_key_content_type_string_formatted,
+        client::set_key_content_type_string_formatted,
+        client::get_hash_key_field_formatted,
+        client::set_hash_key_field_formatted,
     ])
 }
@@ -1,10 +1,15 @@
 use crate::features::cache::FileCacheManager;
 use crate::features::client::RedisClientManager;
+use crate::features::command::ConnectionsStore;
 use crate::features::context::InternalSystemTrayMenuId;
+use crate::features::error::Error;
 use crate::features::events::Events;
+use crate::features::secrets::SecretsVaultState;
 use crate::utils::config::{
     get_connections_file_cache_manager_key, get_connections_file_name,
     get_miscs_file_cache_manager_key, get_miscs_file_name,
+    get_protobuf_schemas_file_cache_manager_key, get_protobuf_schemas_file_name,
+    get_secrets_key_file_name,
 };
 use std::sync::Arc;
 use tauri::{App, Manager, Result, Runtime};
@@ -12,6 +17,39 @@ use tauri_plugin_tauri_redis_setting::{SettingsEvents, SettingsManager};
 use tauri_plugin_tauri_redis_translation::{TranslationEvents, TRANSLATIONS};
 use tauri_redis_core::cache::abstracts::FileCacheBase;
 use tauri_redis_core::cache::impls::FileCache;
+use tauri_redis_core::formatter::FormatterRegistry;
+use tauri_redis_core::vault::secrets::SecretsVault;
+
+fn setup_secrets_vault<R>(app: &mut App<R>) -> Result<()>
+where
+    R: Runtime,
+{
+    let handle = app.handle();
+    let key_file = handle.path_resolver().app_local_data_dir().unwrap().join(
+        get_secrets_key_file_name()
+            .map_err(|err| err.into_anyhow())
+            .unwrap(),
+    );
+
+    let vault = SecretsVault::load_or_generate(key_file)
+        .map_err(|_| Error::FailedToLoadSecretsVault.into_anyhow())
+        .unwrap();
+
+    handle.manage(Arc::new(vault));
+
+    Ok(())
+}
+
+fn setup_formatter_registry<R>(app: &mut App<R>) -> Result<()>
+where
+    R: Runtime,
+{
+    let handle = app.handle();
+
+    handle.manage(Arc::new(FormatterRegistry::with_builtins()));
+
+    Ok(())
+}
 
 fn setup_file_cache_manager<R>(app: &mut App<R>) -> Result<()>
 where
@@ -37,6 +75,38 @@ where
                     .unwrap(),
             );
 
+            // Seal the secrets saved in plaintext by the former versions,
+            // the flat connections list saved by them is migrated along.
+            let vault = handle.state::<SecretsVaultState>();
+            if let Ok(mut connections_store) = file_cache.as_de::<ConnectionsStore>() {
+                let sealed = connections_store
+                    .connections
+                    .iter_mut()
+                    .try_for_each(|connection_info| connection_info.seal_secrets(&vault));
+
+                if sealed.is_ok() && file_cache.replace_se(connections_store).is_ok() {
+                    let _ = file_cache.save(true);
+                }
+            }
+
+            file_cache
+        });
+
+    file_cache_manager
+        .entry(
+            get_protobuf_schemas_file_cache_manager_key()
+                .map_err(|err| err.into_anyhow())
+                .unwrap(),
+        )
+        .or_insert_with(|| {
+            let protobuf_schemas_path = path_resolver.app_local_data_dir().unwrap();
+            let mut file_cache = FileCache::new(protobuf_schemas_path);
+            let _ = file_cache.load_ignore_empty(
+                get_protobuf_schemas_file_name()
+                    .map_err(|err| err.into_anyhow())
+                    .unwrap(),
+            );
+
             file_cache
         });
 
@@ -207,8 +277,12 @@ pub fn init<R>(app: &mut App<R>) -> Result<()>
 where
     R: Runtime,
 {
+    setup_secrets_vault(app)?;
+
     setup_file_cache_manager(app)?;
 
+    setup_formatter_registry(app)?;
+
     setup_redis_client_manager(app)?;
 
     setup_page_metrics(app)?;
@@ -0,0 +1,95 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use fred::types::{RedisKey, RedisValue};
+use serde::{Deserialize, Serialize};
+
+/// Raw bytes of keys and values, which may not be valid UTF-8.
+///
+/// Serialized as `{ raw, display, binary }` where `raw` is base64 encoded and `display` is
+/// a lossy UTF-8 string only for presenting. Deserialized from either the same object or
+/// a plain string, so the callers handling texts only are not affected.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "RedisBytesRepr", into = "RedisBytesView")]
+pub struct RedisBytes(Vec<u8>);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RedisBytesRepr {
+    Text(String),
+    Encoded { raw: String },
+}
+
+#[derive(Serialize)]
+struct RedisBytesView {
+    raw: String,
+    display: String,
+    binary: bool,
+}
+
+impl TryFrom<RedisBytesRepr> for RedisBytes {
+    type Error = base64::DecodeError;
+
+    fn try_from(value: RedisBytesRepr) -> Result<Self, Self::Error> {
+        match value {
+            RedisBytesRepr::Text(text) => Ok(Self(text.into_bytes())),
+            RedisBytesRepr::Encoded { raw } => BASE64.decode(raw).map(Self),
+        }
+    }
+}
+
+impl From<RedisBytes> for RedisBytesView {
+    fn from(value: RedisBytes) -> Self {
+        Self {
+            raw: BASE64.encode(&value.0),
+            display: value.display(),
+            binary: std::str::from_utf8(&value.0).is_err(),
+        }
+    }
+}
+
+impl RedisBytes {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn display(&self) -> String {
+        String::from_utf8_lossy(&self.0).into_owned()
+    }
+
+    /// Numbers are converted to their string forms, nil and nested values are ignored.
+    pub fn from_value(value: RedisValue) -> Option<Self> {
+        match value {
+            RedisValue::Bytes(bytes) => Some(Self(bytes.to_vec())),
+            RedisValue::String(str) => Some(Self(str.as_bytes().to_vec())),
+            value => value.as_string().map(|str| Self(str.into_bytes())),
+        }
+    }
+}
+
+impl From<Vec<u8>> for RedisBytes {
+    fn from(value: Vec<u8>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&RedisKey> for RedisBytes {
+    fn from(value: &RedisKey) -> Self {
+        Self(value.as_bytes().to_vec())
+    }
+}
+
+impl From<RedisBytes> for RedisKey {
+    fn from(value: RedisBytes) -> Self {
+        RedisKey::from(value.0.into_boxed_slice())
+    }
+}
+
+impl From<RedisBytes> for RedisValue {
+    fn from(value: RedisBytes) -> Self {
+        RedisValue::Bytes(value.0.into())
+    }
+}
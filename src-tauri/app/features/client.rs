@@ -1,30 +1,58 @@
 #![allow(dead_code)]
 
-use crate::features::command::Guid;
+use crate::features::bytes::RedisBytes;
+use crate::features::command::{
+    ConnectionKind, ConnectionReconnectInfo, ConnectionTlsInfo, Guid, RedisKeyNamespaceNode,
+    RedisKeyNamespaceNodeKind, RedisKeyspaceInfo, RedisScanFilter, RedisScanPatternMode,
+    RedisZSetMember, ScanId,
+};
 use crate::features::error::{Error, Result};
-use crate::utils::config::get_redis_connection_timeout;
-use fred::interfaces::ClientLike;
+use crate::features::events::RedisConnectionState;
+use crate::features::readonly::RedisReadonlyConn;
+use crate::utils::calculator::calc_glob_escaped;
+use crate::utils::config::{get_redis_connection_timeout, get_redis_scanner_idle_timeout};
+use fred::error::RedisErrorKind;
+use fred::interfaces::{
+    ClientLike, EventInterface, HashesInterface, ServerInterface, SetsInterface,
+    SortedSetsInterface,
+};
+use fred::prelude::RedisResult;
 use fred::tracing::Level;
 use fred::types::{
-    Blocking, Builder, RedisConfig, RedisKey, RedisMap, RedisValue, RespVersion, ScanType, Scanner,
-    ServerConfig, TracingConfig,
+    Blocking, Builder, ClientState, ClusterDiscoveryPolicy, ReconnectPolicy, RedisConfig, RedisKey,
+    RedisMap, RedisValue, RespVersion, SScanResult, ScanType, Scanner, Server, ServerConfig,
+    TlsConfig, TlsConnector, TracingConfig, UnresponsiveConfig, ZScanResult,
 };
+use futures::stream::BoxStream;
 use futures::StreamExt;
 use once_cell::sync::Lazy;
-use serde::Serialize;
-use std::collections::{hash_map, HashMap};
+use serde::{Deserialize, Serialize};
+use std::collections::{hash_map, HashMap, HashSet};
 use std::ops::{Deref, DerefMut};
-use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tauri::command::{CommandArg, CommandItem};
 use tauri::{InvokeError, Runtime};
+use tauri_redis_core::tunnel::ssh::SshTunnel;
+use tauri_redis_core::vault::secrets::Secret;
 use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
-use tokio::task::JoinHandle;
+use tokio::sync::Notify;
+use tokio::task::{AbortHandle, JoinHandle};
 
 static PENDING_REDIS_CONNECTION_TASKS: Lazy<Arc<std::sync::Mutex<Vec<Guid>>>> =
     Lazy::new(|| Arc::new(std::sync::Mutex::new(vec![])));
 
+type RedisScanTasks = HashMap<(Guid, ScanId), Arc<AbortHandle>>;
+
+// The running scanning tasks, so they can be cancelled while
+// the scanning command is still holding the client manager.
+static RUNNING_REDIS_SCAN_TASKS: Lazy<Arc<std::sync::Mutex<RedisScanTasks>>> =
+    Lazy::new(|| Arc::new(std::sync::Mutex::new(HashMap::new())));
+
+const REDIS_SCAN_PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
+
 pub struct RedisInfoDict {
     map: HashMap<String, RedisValue>,
 }
@@ -77,6 +105,37 @@ impl RedisInfoDict {
     pub fn is_empty(&self) -> bool {
         self.map.is_empty()
     }
+
+    /// Parses the `db0:keys=1,expires=0,avg_ttl=0` lines, ordered by db.
+    pub fn keyspace(&self) -> Vec<RedisKeyspaceInfo> {
+        let mut keyspace: Vec<RedisKeyspaceInfo> = self
+            .map
+            .iter()
+            .filter_map(|(key, value)| {
+                let db = key.strip_prefix("db")?.parse::<u8>().ok()?;
+                let mut info = RedisKeyspaceInfo {
+                    db,
+                    ..Default::default()
+                };
+
+                for field in value.as_str()?.split(',') {
+                    let (name, value) = field.split_once('=')?;
+                    let value = value.trim().parse::<u64>().unwrap_or_default();
+                    match name.trim() {
+                        "keys" => info.keys = value,
+                        "expires" => info.expires = value,
+                        "avg_ttl" => info.avg_ttl = value,
+                        _ => {}
+                    }
+                }
+
+                Some(info)
+            })
+            .collect();
+
+        keyspace.sort_by_key(|info| info.db);
+        keyspace
+    }
 }
 
 impl Deref for RedisInfoDict {
@@ -91,6 +150,11 @@ impl Deref for RedisInfoDict {
 pub enum RedisKeyType {
     String,
     Hash,
+    List,
+    Set,
+    ZSet,
+    Stream,
+    Json,
     Unknown,
 }
 
@@ -104,6 +168,12 @@ where
         match value.to_lowercase().as_str() {
             "hash" => RedisKeyType::Hash,
             "string" => RedisKeyType::String,
+            "list" => RedisKeyType::List,
+            "set" => RedisKeyType::Set,
+            "zset" => RedisKeyType::ZSet,
+            "stream" => RedisKeyType::Stream,
+            // Named by the RedisJSON module.
+            "rejson-rl" => RedisKeyType::Json,
             _ => RedisKeyType::Unknown,
         }
     }
@@ -113,6 +183,50 @@ impl RedisKeyType {
     pub fn is_valid(&self) -> bool {
         *self != RedisKeyType::Unknown
     }
+
+    /// Only the core types can be filtered by `SCAN ... TYPE`.
+    pub fn scan_type(&self) -> Option<ScanType> {
+        match self {
+            RedisKeyType::String => Some(ScanType::String),
+            RedisKeyType::Hash => Some(ScanType::Hash),
+            RedisKeyType::List => Some(ScanType::List),
+            RedisKeyType::Set => Some(ScanType::Set),
+            RedisKeyType::ZSet => Some(ScanType::ZSet),
+            RedisKeyType::Stream => Some(ScanType::Stream),
+            RedisKeyType::Json | RedisKeyType::Unknown => None,
+        }
+    }
+}
+
+impl RedisScanFilter {
+    /// The glob pattern of the searching input, all keys by default.
+    pub fn pattern(&self, input: Option<String>) -> String {
+        match input.filter(|input| !input.is_empty()) {
+            None => "*".to_string(),
+            Some(input) if self.mode == RedisScanPatternMode::Exact => input,
+            Some(input) => format!("*{}*", calc_glob_escaped(&input)),
+        }
+    }
+
+    pub fn scan_type(&self) -> Result<Option<ScanType>> {
+        self.key_type
+            .as_ref()
+            .map(|key_type| {
+                RedisKeyType::from(key_type.as_str())
+                    .scan_type()
+                    .ok_or(Error::InvalidRedisKeyType)
+            })
+            .transpose()
+    }
+
+    /// Keys are matched as bytes, so the binary ones are not skipped.
+    pub fn regex(&self) -> Result<Option<regex::bytes::Regex>> {
+        self.regex
+            .as_ref()
+            .filter(|regex| !regex.is_empty())
+            .map(|regex| regex::bytes::Regex::new(regex).map_err(|_| Error::InvalidRegexPattern))
+            .transpose()
+    }
 }
 
 impl<'de, R: Runtime> CommandArg<'de, R> for RedisKeyType {
@@ -134,10 +248,193 @@ pub struct RedisClientConnectionPayload {
     pub port: u16,
     pub username: Option<String>,
     pub password: Option<String>,
+    pub readonly: bool,
+    pub db: u8,
+    pub kind: ConnectionKind,
+    pub tls: Option<ConnectionTlsInfo>,
+    pub reconnect: Option<ConnectionReconnectInfo>,
 
     pub guid: Guid,
 }
 
+impl RedisClientConnectionPayload {
+    fn enabled_tls(&self) -> Option<&ConnectionTlsInfo> {
+        self.tls.as_ref().filter(|tls| tls.enabled)
+    }
+
+    fn build_reconnect_policy(&self) -> Option<ReconnectPolicy> {
+        self.reconnect
+            .as_ref()
+            .filter(|reconnect| reconnect.enabled)
+            .map(|reconnect| {
+                ReconnectPolicy::new_exponential(
+                    reconnect.max_attempts,
+                    reconnect.min_delay,
+                    reconnect.max_delay.max(reconnect.min_delay),
+                    2,
+                )
+            })
+    }
+
+    fn build_server_config(&self) -> Result<ServerConfig> {
+        let server_name = self
+            .enabled_tls()
+            .and_then(|tls| tls.server_name.clone())
+            .filter(|name| !name.is_empty());
+
+        match &self.kind {
+            ConnectionKind::Standalone => Ok(ServerConfig::Centralized {
+                server: Server::new_with_tls(self.host.clone(), self.port, server_name),
+            }),
+            ConnectionKind::Sentinel(sentinel) => {
+                if sentinel.hosts.is_empty() || sentinel.master_name.is_empty() {
+                    return Err(Error::InvalidSentinelConnectionInfo);
+                }
+
+                Ok(ServerConfig::Sentinel {
+                    hosts: sentinel
+                        .hosts
+                        .iter()
+                        .map(|address| {
+                            Server::new_with_tls(
+                                address.host.clone(),
+                                address.port,
+                                server_name.clone(),
+                            )
+                        })
+                        .collect(),
+                    service_name: sentinel.master_name.clone(),
+                    username: sentinel.username.clone().filter(|un| !un.is_empty()),
+                    password: sentinel
+                        .password
+                        .as_ref()
+                        .and_then(Secret::plain)
+                        .filter(|ps| !ps.is_empty())
+                        .map(str::to_string),
+                })
+            }
+            ConnectionKind::Cluster(cluster) => {
+                let hosts = if cluster.hosts.is_empty() {
+                    vec![Server::new_with_tls(
+                        self.host.clone(),
+                        self.port,
+                        server_name,
+                    )]
+                } else {
+                    cluster
+                        .hosts
+                        .iter()
+                        .map(|address| {
+                            Server::new_with_tls(
+                                address.host.clone(),
+                                address.port,
+                                server_name.clone(),
+                            )
+                        })
+                        .collect()
+                };
+
+                Ok(ServerConfig::Clustered {
+                    hosts,
+                    policy: ClusterDiscoveryPolicy::default(),
+                })
+            }
+            #[cfg(unix)]
+            ConnectionKind::Unix(unix) => {
+                if unix.path.is_empty() {
+                    return Err(Error::InvalidUnixSocketPath);
+                }
+
+                Ok(ServerConfig::Unix {
+                    path: unix.path.clone().into(),
+                })
+            }
+            #[cfg(not(unix))]
+            ConnectionKind::Unix(_) => Err(Error::UnsupportedUnixSocketConnection),
+        }
+    }
+
+    fn build_tls_config(&self) -> Result<Option<TlsConfig>> {
+        let tls = match self.enabled_tls() {
+            Some(tls) => tls,
+            None => return Ok(None),
+        };
+
+        let read_file = |path: &Option<String>| -> Result<Option<Vec<u8>>> {
+            match path.as_ref().filter(|path| !path.is_empty()) {
+                Some(path) => std::fs::read(path)
+                    .map(Some)
+                    .map_err(|_| Error::FailedToReadTlsCertificateFile),
+                None => Ok(None),
+            }
+        };
+
+        let mut builder = native_tls::TlsConnector::builder();
+
+        if let Some(ca) = read_file(&tls.ca_file)? {
+            // A bundle may contain several certificates, add them one by one.
+            const PEM_END: &str = "-----END CERTIFICATE-----";
+            let ca = String::from_utf8(ca).map_err(|_| Error::InvalidTlsCertificate)?;
+            for pem in ca
+                .split_inclusive(PEM_END)
+                .filter(|pem| pem.contains(PEM_END))
+            {
+                let certificate = native_tls::Certificate::from_pem(pem.trim().as_bytes())
+                    .map_err(|_| Error::InvalidTlsCertificate)?;
+                builder.add_root_certificate(certificate);
+            }
+        }
+
+        let client_cert = read_file(&tls.client_cert_file)?;
+        let client_key = match (
+            read_file(&tls.client_key_file)?,
+            tls.client_key_passphrase
+                .as_ref()
+                .and_then(Secret::plain)
+                .filter(|passphrase| !passphrase.is_empty()),
+        ) {
+            (Some(key), Some(passphrase)) => Some(calc_decrypted_client_key(&key, passphrase)?),
+            (key, _) => key,
+        };
+        match (client_cert, client_key) {
+            (Some(cert), Some(key)) => {
+                let identity = native_tls::Identity::from_pkcs8(&cert, &key)
+                    .map_err(|_| Error::InvalidTlsCertificate)?;
+                builder.identity(identity);
+            }
+            (None, None) => {}
+            // Only one of the pair was provided.
+            _ => return Err(Error::InvalidTlsCertificate),
+        }
+
+        builder
+            .danger_accept_invalid_certs(tls.insecure_skip_verify)
+            .danger_accept_invalid_hostnames(tls.insecure_skip_verify);
+
+        let connector = TlsConnector::try_from(builder).map_err(Error::RedisInternalError)?;
+
+        Ok(Some(connector.into()))
+    }
+}
+
+/// native-tls only accepts the unencrypted PKCS#8 keys.
+fn calc_decrypted_client_key(key: &[u8], passphrase: &str) -> Result<Vec<u8>> {
+    let pem = std::str::from_utf8(key).map_err(|_| Error::InvalidTlsCertificate)?;
+    let (_, document) =
+        pkcs8::SecretDocument::from_pem(pem).map_err(|_| Error::InvalidTlsCertificate)?;
+
+    let decrypted = pkcs8::EncryptedPrivateKeyInfo::try_from(document.as_bytes())
+        .map_err(|_| Error::InvalidTlsCertificate)?
+        .decrypt(passphrase)
+        .map_err(|_| Error::FailedToDecryptTlsClientKey)?;
+
+    let pem = decrypted
+        .to_pem("PRIVATE KEY", pkcs8::LineEnding::LF)
+        .map_err(|_| Error::FailedToDecryptTlsClientKey)?;
+
+    Ok(pem.as_bytes().to_vec())
+}
+
 #[derive(Debug)]
 pub struct RedisScannerResult {
     keys: Vec<RedisKey>,
@@ -189,13 +486,47 @@ impl Default for RedisHScannerResult {
     }
 }
 
+#[derive(Debug)]
+pub struct RedisMemberScannerResult<M> {
+    members: Vec<M>,
+    can_continue: bool,
+}
+
+impl<M> RedisMemberScannerResult<M> {
+    pub fn members(&self) -> &Vec<M> {
+        &self.members
+    }
+    pub fn take_members(self) -> Vec<M> {
+        self.members
+    }
+    pub fn can_continue(&self) -> bool {
+        self.can_continue
+    }
+}
+
+impl<M> Default for RedisMemberScannerResult<M> {
+    fn default() -> Self {
+        Self {
+            members: vec![],
+            can_continue: true,
+        }
+    }
+}
+
+pub type RedisSScannerResult = RedisMemberScannerResult<RedisBytes>;
+
+pub type RedisZScannerResult = RedisMemberScannerResult<RedisZSetMember>;
+
 pub struct RedisScanner {
     pattern: String,
     iter_count: u32,
     r#type: Option<ScanType>,
+    // Checked on the scanned keys, a page may be returned with fewer keys.
+    regex: Option<regex::bytes::Regex>,
     rx: UnboundedReceiver<Result<RedisScannerResult>>,
-    signal: Arc<AtomicBool>,
+    signal: Arc<Notify>,
     handler: Option<JoinHandle<()>>,
+    // The kept keys of all the pages, only for `refresh_scan`.
     scanned_count: Arc<AtomicU32>,
 }
 
@@ -204,17 +535,277 @@ pub struct RedisHScanner {
     pattern: String,
     iter_count: u32,
     rx: UnboundedReceiver<Result<RedisHScannerResult>>,
-    signal: Arc<AtomicBool>,
+    signal: Arc<Notify>,
+    handler: Option<JoinHandle<()>>,
+    // The scanned fields of all the pages, only for `refresh_hscan`.
+    scanned_count: Arc<AtomicU32>,
+}
+
+/// Scans the members of a collection key, see `RedisScanMember`.
+pub struct RedisMemberScanner<M> {
+    key: RedisKey,
+    pattern: String,
+    iter_count: u32,
+    rx: UnboundedReceiver<Result<RedisMemberScannerResult<M>>>,
+    signal: Arc<Notify>,
     handler: Option<JoinHandle<()>>,
+    // The distinct members of all the pages, only for `refresh_member_scan`.
     scanned_count: Arc<AtomicU32>,
 }
 
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RedisScannerKind {
+    Keys,
+    Hash,
+    Set,
+    ZSet,
+}
+
+impl RedisScannerKind {
+    /// Used by the callers without a scan id, one scanner per kind.
+    pub fn default_scan_id(&self) -> ScanId {
+        match self {
+            RedisScannerKind::Keys => "keys",
+            RedisScannerKind::Hash => "hash",
+            RedisScannerKind::Set => "set",
+            RedisScannerKind::ZSet => "zset",
+        }
+        .to_string()
+    }
+}
+
+pub enum RedisScannerSlot {
+    Keys(RedisScanner),
+    Hash(RedisHScanner),
+    Set(RedisMemberScanner<RedisBytes>),
+    ZSet(RedisMemberScanner<RedisZSetMember>),
+}
+
+#[derive(Debug)]
+pub enum RedisScannerPage {
+    Keys(RedisScannerResult),
+    Hash(RedisHScannerResult),
+    Set(RedisSScannerResult),
+    ZSet(RedisZScannerResult),
+}
+
+/// A member type of the collections scanned by `RedisMemberScanner`.
+pub trait RedisScanMember: Sized + Send + 'static {
+    type Scanned: Scanner + Send + 'static;
+
+    const KIND: RedisScannerKind;
+
+    fn scan(
+        conn: &fred::clients::RedisClient,
+        key: RedisKey,
+        pattern: String,
+        count: u32,
+    ) -> BoxStream<'static, RedisResult<Self::Scanned>>;
+
+    fn from_page(page: <Self::Scanned as Scanner>::Page) -> Vec<Self>;
+
+    /// The same member may be returned more than once while scanning.
+    fn member(&self) -> &RedisBytes;
+
+    fn as_scanner(slot: &mut RedisScannerSlot) -> Option<&mut RedisMemberScanner<Self>>;
+
+    fn into_slot(scanner: RedisMemberScanner<Self>) -> RedisScannerSlot;
+}
+
+impl RedisScanMember for RedisBytes {
+    type Scanned = SScanResult;
+
+    const KIND: RedisScannerKind = RedisScannerKind::Set;
+
+    fn scan(
+        conn: &fred::clients::RedisClient,
+        key: RedisKey,
+        pattern: String,
+        count: u32,
+    ) -> BoxStream<'static, RedisResult<Self::Scanned>> {
+        conn.sscan(key, pattern, Some(count)).boxed()
+    }
+
+    fn from_page(page: Vec<RedisValue>) -> Vec<Self> {
+        page.into_iter()
+            .filter_map(RedisBytes::from_value)
+            .collect()
+    }
+
+    fn member(&self) -> &RedisBytes {
+        self
+    }
+
+    fn as_scanner(slot: &mut RedisScannerSlot) -> Option<&mut RedisMemberScanner<Self>> {
+        match slot {
+            RedisScannerSlot::Set(sscanner) => Some(sscanner),
+            _ => None,
+        }
+    }
+
+    fn into_slot(scanner: RedisMemberScanner<Self>) -> RedisScannerSlot {
+        RedisScannerSlot::Set(scanner)
+    }
+}
+
+impl RedisScanMember for RedisZSetMember {
+    type Scanned = ZScanResult;
+
+    const KIND: RedisScannerKind = RedisScannerKind::ZSet;
+
+    fn scan(
+        conn: &fred::clients::RedisClient,
+        key: RedisKey,
+        pattern: String,
+        count: u32,
+    ) -> BoxStream<'static, RedisResult<Self::Scanned>> {
+        conn.zscan(key, pattern, Some(count)).boxed()
+    }
+
+    fn from_page(page: Vec<(RedisValue, f64)>) -> Vec<Self> {
+        page.into_iter()
+            .filter_map(|(member, score)| {
+                RedisBytes::from_value(member).map(|member| RedisZSetMember { member, score })
+            })
+            .collect()
+    }
+
+    fn member(&self) -> &RedisBytes {
+        &self.member
+    }
+
+    fn as_scanner(slot: &mut RedisScannerSlot) -> Option<&mut RedisMemberScanner<Self>> {
+        match slot {
+            RedisScannerSlot::ZSet(zscanner) => Some(zscanner),
+            _ => None,
+        }
+    }
+
+    fn into_slot(scanner: RedisMemberScanner<Self>) -> RedisScannerSlot {
+        RedisScannerSlot::ZSet(scanner)
+    }
+}
+
+impl RedisScannerSlot {
+    fn abort_handle(&self) -> Option<AbortHandle> {
+        let handler = match self {
+            RedisScannerSlot::Keys(scanner) => &scanner.handler,
+            RedisScannerSlot::Hash(hscanner) => &hscanner.handler,
+            RedisScannerSlot::Set(sscanner) => &sscanner.handler,
+            RedisScannerSlot::ZSet(zscanner) => &zscanner.handler,
+        };
+
+        handler.as_ref().map(JoinHandle::abort_handle)
+    }
+}
+
+/// Registered while the scanner is kept.
+struct RedisScanTask {
+    id: (Guid, ScanId),
+    abort_handle: Arc<AbortHandle>,
+}
+
+impl RedisScanTask {
+    fn register(guid: &Guid, scan_id: &ScanId, abort_handle: AbortHandle) -> Self {
+        let id = (guid.clone(), scan_id.clone());
+        let abort_handle = Arc::new(abort_handle);
+
+        RUNNING_REDIS_SCAN_TASKS
+            .lock()
+            .unwrap()
+            .insert(id.clone(), abort_handle.clone());
+
+        Self { id, abort_handle }
+    }
+
+    // The task only ends when it is aborted.
+    fn is_cancelled(&self) -> bool {
+        self.abort_handle.is_finished()
+    }
+}
+
+struct RedisScannerEntry {
+    slot: RedisScannerSlot,
+    task: Option<RedisScanTask>,
+    last_used_at: Instant,
+    // The running key counts of the namespace folders, by their full names.
+    folder_counts: HashMap<RedisBytes, u64>,
+}
+
+pub type RedisScanReporter = Arc<dyn Fn(RedisScanProgress) + Send + Sync>;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisScanProgress {
+    pub scan_id: ScanId,
+    pub kind: RedisScannerKind,
+    // Returned by the server so far, an item may be returned more than once.
+    pub scanned_count: u64,
+    // The cursor iterations so far.
+    pub iterations: u64,
+    // By DBSIZE or the length of the key, unknown in a cluster.
+    pub total: Option<u64>,
+    pub percent: Option<f64>,
+    pub finished: bool,
+}
+
+/// Owned by the scanning task, reports at most once per interval.
+struct RedisScanProgressTracker {
+    progress: RedisScanProgress,
+    reporter: Option<RedisScanReporter>,
+    reported_at: Instant,
+}
+
+impl RedisScanProgressTracker {
+    fn record(&mut self, count: usize) {
+        self.progress.scanned_count += count as u64;
+        self.progress.iterations += 1;
+        self.progress.percent = self.progress.total.map(|total| match total {
+            0 => 100.0,
+            total => (self.progress.scanned_count as f64 / total as f64 * 100.0).min(100.0),
+        });
+
+        if self.reported_at.elapsed() >= REDIS_SCAN_PROGRESS_INTERVAL {
+            self.flush();
+        }
+    }
+
+    /// Always reported when a page is sent.
+    fn report_page(&mut self, can_continue: bool) {
+        if !can_continue {
+            self.progress.finished = true;
+            self.progress.percent = self.progress.total.map(|_| 100.0);
+        }
+
+        self.flush();
+    }
+
+    fn flush(&mut self) {
+        if let Some(reporter) = &self.reporter {
+            reporter(self.progress.clone());
+        }
+
+        self.reported_at = Instant::now();
+    }
+}
+
 pub struct RedisClient {
+    guid: Guid,
     manager: fred::clients::RedisClient,
-    scanner: Option<RedisScanner>,
-    hscanner: Option<RedisHScanner>,
+    readonly: bool,
+    db: u8,
+    // Keyed by the caller supplied scan id, so the views scan independently.
+    scanners: HashMap<ScanId, RedisScannerEntry>,
+    scan_reporter: Option<RedisScanReporter>,
+    tunnel: Option<SshTunnel>,
+    // Kept to watch the client rebuilt by `select_db`.
+    state_notifier: Option<RedisStateNotifier>,
+    watchers: Vec<JoinHandle<RedisResult<()>>>,
 }
 
+pub type RedisStateNotifier = Arc<dyn Fn(RedisConnectionState) + Send + Sync>;
+
 pub type RedisClientManagerState = Arc<tauri::async_runtime::Mutex<RedisClientManager>>;
 
 #[derive(Default)]
@@ -224,9 +815,16 @@ pub struct RedisClientManager {
 
 impl RedisClient {
     pub async fn new(payload: RedisClientConnectionPayload) -> Result<Self> {
+        let tls = payload.build_tls_config()?;
+        let server = payload.build_server_config()?;
+        let policy = payload.build_reconnect_policy();
+        // Only db 0 is available in a cluster.
+        let database = Some(payload.db)
+            .filter(|db| *db != 0 && !matches!(payload.kind, ConnectionKind::Cluster(_)));
+
         let config = RedisConfig {
             fail_fast: true,
-            server: ServerConfig::new_centralized(payload.host, payload.port),
+            server,
             blocking: Blocking::Block,
             username: {
                 payload
@@ -239,7 +837,8 @@ impl RedisClient {
                     .and_then(|ps| if ps.is_empty() { None } else { Some(ps) })
             },
             version: RespVersion::RESP2,
-            database: None,
+            database,
+            tls,
             tracing: TracingConfig {
                 enabled: true,
                 default_tracing_level: Level::INFO,
@@ -247,382 +846,556 @@ impl RedisClient {
             },
         };
 
-        let client = Builder::from_config(config)
+        let mut builder = Builder::from_config(config);
+        if let Some(policy) = policy {
+            builder.set_policy(policy);
+        }
+
+        let client = builder
             .with_connection_config(|config| {
-                config.connection_timeout =
-                    Duration::from_secs(get_redis_connection_timeout().unwrap().into());
-                config.internal_command_timeout =
-                    Duration::from_secs(get_redis_connection_timeout().unwrap().into());
+                let timeout = Duration::from_secs(get_redis_connection_timeout().unwrap().into());
+                config.connection_timeout = timeout;
+                config.internal_command_timeout = timeout;
+                // Otherwise a silently dropped connection is never reported as unresponsive.
+                config.unresponsive = UnresponsiveConfig {
+                    max_timeout: Some(timeout),
+                    interval: (timeout / 2).min(UnresponsiveConfig::default().interval),
+                };
             })
             .build()
             .map_err(Error::RedisInternalError)?;
 
         // Connect.
-        client.connect();
-
-        client
-            .wait_for_connect()
-            .await
-            .map_err(Error::RedisInternalError)?;
+        // Unlike `connect`, it stops retrying in the background if the first attempt failed.
+        client.init().await.map_err(Error::RedisInternalError)?;
 
         Ok(Self {
+            guid: payload.guid,
             manager: client,
-            scanner: None,
-            hscanner: None,
+            readonly: payload.readonly,
+            db: database.unwrap_or_default(),
+            scanners: HashMap::new(),
+            scan_reporter: None,
+            tunnel: None,
+            state_notifier: None,
+            watchers: vec![],
         })
     }
 
-    pub fn conn(&mut self) -> Result<&mut fred::clients::RedisClient> {
-        Ok(&mut self.manager)
+    pub fn db(&self) -> u8 {
+        self.db
     }
 
-    async fn _invoke_new_scan(
-        &mut self,
-        pattern: String,
-        iter_count: u32,
-        needed_count: u32,
-        r#type: Option<ScanType>,
-    ) -> Result<()> {
-        let (sx, rx) = unbounded_channel::<Result<RedisScannerResult>>();
+    /// Selects another db, the scanners of the former one are dropped.
+    ///
+    /// Fred only selects the configured db when reconnecting, so the client is rebuilt with
+    /// the switched one. Otherwise the commands queued behind a reconnection may run
+    /// against the configured db before it is selected again.
+    pub async fn select_db(&mut self, db: u8) -> Result<()> {
+        // Only db 0 is available in a cluster.
+        if self.manager.is_clustered() {
+            if db != 0 {
+                return Err(Error::ClusterOnlySupportsDefaultDb);
+            }
 
-        let signal = Arc::new(AtomicBool::new(true));
-        let scanned_count = Arc::new(AtomicU32::new(0));
+            return Ok(());
+        }
 
-        let old = self.scanner.replace(RedisScanner {
-            rx,
-            pattern: pattern.clone(),
-            iter_count,
-            r#type: r#type.clone(),
-            signal: signal.clone(),
-            handler: None,
-            scanned_count: scanned_count.clone(),
-        });
+        let mut config = self.manager.client_config();
+        config.database = Some(db).filter(|db| *db != 0);
 
-        drop(old);
+        let mut builder = Builder::from_config(config);
+        if let Some(policy) = self.manager.client_reconnect_policy() {
+            builder.set_policy(policy);
+        }
+        builder
+            .set_connection_config(self.manager.connection_config().clone())
+            .set_performance_config(self.manager.perf_config());
 
-        let conn = self.conn()?;
-        let mut stream = conn.scan(pattern, Some(iter_count), r#type);
-        let signal = signal.clone();
-        let scanned_count = scanned_count.clone();
-        let old = self
-            .scanner
-            .as_mut()
-            .unwrap()
-            .handler
-            .replace(tokio::spawn(async move {
-                loop {
-                    if signal.load(Ordering::Relaxed) {
-                        signal.fetch_and(false, Ordering::Relaxed);
-
-                        let mut sent = false;
-                        let mut keys = Vec::with_capacity(iter_count as usize);
-                        while let Some(result) = stream.next().await {
-                            let result = result.map_err(Error::RedisInternalError);
-                            if result.is_err() {
-                                sx.send(Err(result.err().unwrap())).unwrap();
-                                sent = true;
-
-                                // End `next` loop.
-                                // Means we only request once.
-                                break;
-                            }
+        let client = builder.build().map_err(Error::RedisInternalError)?;
+        client.init().await.map_err(Error::RedisInternalError)?;
 
-                            let mut value = result.unwrap();
-                            let mut scanned_keys = value.take_results().unwrap_or_default();
+        // Not to report the state of the former one.
+        self.stop_watching_state();
+        let former = std::mem::replace(&mut self.manager, client);
+        self.start_watching_state();
 
-                            // Append
-                            keys.append(&mut scanned_keys);
+        self.db = db;
+        self.scanners.clear();
 
-                            // Record scanned count.
-                            // Use keys.len() to use the `.with_capacity` related functionality.
-                            scanned_count.fetch_add(keys.len() as u32, Ordering::Relaxed);
+        let _ = former.quit().await;
 
-                            let can_continue = value.has_more();
-                            if can_continue {
-                                // Continue scanning.
-                                let _ = value.next();
-                            }
+        Ok(())
+    }
 
-                            // If we scanned enough items
-                            // or cannot scan anymore.
-                            let scanned_count_raw = scanned_count.load(Ordering::Relaxed);
-                            let scanned_enough = scanned_count_raw >= needed_count || !can_continue;
-                            if scanned_enough {
-                                sx.send(Ok(RedisScannerResult { keys, can_continue }))
-                                    .unwrap();
-                                sent = true;
-
-                                // End `next` loop.
-                                break;
-                            }
-                        }
+    /// Reports the state changes happened after connected.
+    pub fn watch_state<F>(&mut self, notify: F)
+    where
+        F: Fn(RedisConnectionState) + Send + Sync + 'static,
+    {
+        self.stop_watching_state();
+        self.state_notifier = Some(Arc::new(notify));
+        self.start_watching_state();
+    }
 
-                        if !sent {
-                            sx.send(Ok(Default::default())).unwrap();
-                        }
-                    } else {
-                        tokio::time::sleep(Duration::from_millis(500)).await;
-                    }
+    fn start_watching_state(&mut self) {
+        let Some(notify) = self.state_notifier.clone() else {
+            return;
+        };
+
+        let on_reconnect = notify.clone();
+        self.watchers.push(self.manager.on_reconnect(move |_| {
+            on_reconnect(RedisConnectionState::Connected);
+            Ok(())
+        }));
+
+        let on_unresponsive = notify.clone();
+        self.watchers.push(self.manager.on_unresponsive(move |_| {
+            on_unresponsive(RedisConnectionState::Reconnecting);
+            Ok(())
+        }));
+
+        // Aborted with the watchers, so it won't keep the client alive.
+        let client = self.manager.clone();
+        self.watchers.push(self.manager.on_error(move |err| {
+            notify(match (err.kind(), client.state()) {
+                (RedisErrorKind::Auth, _) => RedisConnectionState::AuthFailed,
+                // No reconnect policy, or out of attempts.
+                (_, ClientState::Disconnected | ClientState::Disconnecting) => {
+                    RedisConnectionState::Disconnected
                 }
-            }));
+                _ => RedisConnectionState::Reconnecting,
+            });
+            Ok(())
+        }));
+    }
+
+    fn stop_watching_state(&mut self) {
+        self.watchers.drain(..).for_each(|watcher| watcher.abort());
+    }
+
+    /// Reports the progress of the scans started afterwards.
+    pub fn report_scan_progress<F>(&mut self, report: F)
+    where
+        F: Fn(RedisScanProgress) + Send + Sync + 'static,
+    {
+        self.scan_reporter = Some(Arc::new(report));
+    }
+
+    pub fn attach_tunnel(&mut self, tunnel: SshTunnel) {
+        let old = self.tunnel.replace(tunnel);
 
         drop(old);
+    }
 
-        Ok(())
+    /// Quits the client, then tears down the tunnel.
+    /// The tunnel is torn down even if the client failed to quit.
+    pub async fn close(mut self) -> Result<()> {
+        self.stop_watching_state();
+
+        let quitted = self.manager.quit().await;
+
+        if let Some(tunnel) = self.tunnel.take() {
+            tunnel.close().await.map_err(Error::AnyhowError)?;
+        }
+
+        quitted.map_err(Error::RedisInternalError)
+    }
+
+    /// Only the commands which never write are available, see `RedisReadonlyConn`.
+    pub fn conn(&self) -> Result<RedisReadonlyConn<'_>> {
+        Ok(RedisReadonlyConn::new(&self.manager))
+    }
+
+    /// The only way to write, which refuses readonly connections.
+    pub fn writable_conn(&mut self) -> Result<&mut fred::clients::RedisClient> {
+        if self.readonly {
+            return Err(Error::ReadonlyConnection);
+        }
+
+        Ok(&mut self.manager)
+    }
+
+    fn sweep_idle_scanners(&mut self) {
+        let Ok(idle_timeout) = get_redis_scanner_idle_timeout() else {
+            return;
+        };
+
+        let idle_timeout = Duration::from_secs(idle_timeout);
+        self.scanners
+            .retain(|_, entry| entry.last_used_at.elapsed() < idle_timeout);
+    }
+
+    fn insert_scanner(&mut self, scan_id: &ScanId, slot: RedisScannerSlot) {
+        self.sweep_idle_scanners();
+
+        let task = slot
+            .abort_handle()
+            .map(|abort_handle| RedisScanTask::register(&self.guid, scan_id, abort_handle));
+
+        self.scanners.insert(
+            scan_id.clone(),
+            RedisScannerEntry {
+                slot,
+                task,
+                last_used_at: Instant::now(),
+                folder_counts: HashMap::new(),
+            },
+        );
+    }
+
+    /// The cancelled ones are closed instead, and the idle ones are swept beforehand.
+    fn touch_scanner(&mut self, scan_id: &ScanId) -> Option<&mut RedisScannerSlot> {
+        self.sweep_idle_scanners();
+
+        let cancelled = self
+            .scanners
+            .get(scan_id)
+            .is_some_and(|entry| entry.task.as_ref().is_some_and(RedisScanTask::is_cancelled));
+        if cancelled {
+            self.close_scanner(scan_id);
+        }
+
+        self.scanners.get_mut(scan_id).map(|entry| {
+            entry.last_used_at = Instant::now();
+
+            &mut entry.slot
+        })
+    }
+
+    /// The background scanning task is stopped once dropped.
+    /// The idle scanners are swept along.
+    pub fn close_scanner(&mut self, scan_id: &ScanId) -> bool {
+        let closed = self.scanners.remove(scan_id).is_some();
+        self.sweep_idle_scanners();
+
+        closed
+    }
+
+    /// Replaces the folder counts of a namespace page with the running totals of the scanner,
+    /// which are reset along with the scanner.
+    pub fn accumulate_folder_counts(
+        &mut self,
+        scan_id: &ScanId,
+        nodes: &mut [RedisKeyNamespaceNode],
+    ) {
+        let Some(entry) = self.scanners.get_mut(scan_id) else {
+            return;
+        };
+
+        for node in nodes
+            .iter_mut()
+            .filter(|node| node.kind == RedisKeyNamespaceNodeKind::Folder)
+        {
+            let total = entry
+                .folder_counts
+                .entry(node.full_name.clone())
+                .or_default();
+            *total += node.count;
+            node.count = *total;
+        }
+    }
+
+    /// Continues with the same key and pattern as created.
+    pub async fn continue_scan(&mut self, scan_id: &ScanId) -> Result<RedisScannerPage> {
+        match self.touch_scanner(scan_id) {
+            Some(RedisScannerSlot::Keys(scanner)) => {
+                let (pattern, iter_count, r#type, regex) = (
+                    scanner.pattern.clone(),
+                    scanner.iter_count,
+                    scanner.r#type.clone(),
+                    scanner.regex.clone(),
+                );
+
+                Ok(RedisScannerPage::Keys(
+                    self.scan(scan_id, pattern, iter_count, iter_count, r#type, regex)
+                        .await?,
+                ))
+            }
+            Some(RedisScannerSlot::Hash(hscanner)) => {
+                let (key, pattern, iter_count) = (
+                    hscanner.key.clone(),
+                    hscanner.pattern.clone(),
+                    hscanner.iter_count,
+                );
+
+                Ok(RedisScannerPage::Hash(
+                    self.hscan(scan_id, key, pattern, iter_count, iter_count)
+                        .await?,
+                ))
+            }
+            Some(RedisScannerSlot::Set(sscanner)) => {
+                let (key, pattern, iter_count) = (
+                    sscanner.key.clone(),
+                    sscanner.pattern.clone(),
+                    sscanner.iter_count,
+                );
+
+                Ok(RedisScannerPage::Set(
+                    self.member_scan(scan_id, key, pattern, iter_count, iter_count)
+                        .await?,
+                ))
+            }
+            Some(RedisScannerSlot::ZSet(zscanner)) => {
+                let (key, pattern, iter_count) = (
+                    zscanner.key.clone(),
+                    zscanner.pattern.clone(),
+                    zscanner.iter_count,
+                );
+
+                Ok(RedisScannerPage::ZSet(
+                    self.member_scan(scan_id, key, pattern, iter_count, iter_count)
+                        .await?,
+                ))
+            }
+            None => Err(Error::FailedToFindRedisScanner),
+        }
     }
 
     pub async fn scan(
         &mut self,
+        scan_id: &ScanId,
         pattern: String,
         iter_count: u32,
         needed_count: u32,
         r#type: Option<ScanType>,
+        regex: Option<regex::bytes::Regex>,
     ) -> Result<RedisScannerResult> {
         let iter_count = iter_count.max(needed_count);
 
-        if self.scanner.is_some() {
-            let scanner = self.scanner.as_mut().unwrap();
-
-            if scanner.pattern != pattern
-                || scanner.iter_count != iter_count
-                || scanner.r#type != r#type
-            {
-                self._invoke_new_scan(pattern, iter_count, needed_count, r#type)
-                    .await?;
-            }
-        } else {
-            self._invoke_new_scan(pattern, iter_count, needed_count, r#type)
+        let reusable = matches!(
+            self.touch_scanner(scan_id),
+            Some(RedisScannerSlot::Keys(scanner)) if scanner.pattern == pattern
+                && scanner.iter_count == iter_count
+                && scanner.r#type == r#type
+                && scanner.regex.as_ref().map(regex::bytes::Regex::as_str)
+                    == regex.as_ref().map(regex::bytes::Regex::as_str)
+        );
+        if !reusable {
+            self._invoke_new_scan(scan_id, pattern, iter_count, needed_count, r#type, regex)
                 .await?;
         }
 
         // Continue scan.
-        let scanner = self.scanner.as_mut().unwrap();
-        scanner.signal.fetch_or(true, Ordering::Relaxed);
-
-        while let Some(result) = scanner.rx.recv().await {
-            return result;
-        }
+        let Some(RedisScannerSlot::Keys(scanner)) = self.touch_scanner(scan_id) else {
+            return Err(Error::FailedToGetRedisScanResult);
+        };
+        scanner.signal.notify_one();
 
-        Err(Error::FailedToGetRedisScanResult)
+        // Closed once the scanning task is cancelled.
+        scanner
+            .rx
+            .recv()
+            .await
+            .unwrap_or(Err(Error::RedisScanCancelled))
     }
 
     pub async fn force_new_scan(
         &mut self,
+        scan_id: &ScanId,
         pattern: String,
         iter_count: u32,
         needed_count: u32,
         r#type: Option<ScanType>,
+        regex: Option<regex::bytes::Regex>,
     ) -> Result<RedisScannerResult> {
-        let old = self.scanner.take();
+        self.close_scanner(scan_id);
 
-        drop(old);
-
-        self.scan(pattern, iter_count, needed_count, r#type).await
+        self.scan(scan_id, pattern, iter_count, needed_count, r#type, regex)
+            .await
     }
 
     pub async fn refresh_scan(
         &mut self,
+        scan_id: &ScanId,
         pattern: String,
         iter_count: u32,
         offset: Option<u32>,
         r#type: Option<ScanType>,
+        regex: Option<regex::bytes::Regex>,
     ) -> Result<RedisScannerResult> {
-        let needed_count = if self.scanner.is_some() {
-            self.scanner
-                .as_ref()
-                .unwrap()
-                .scanned_count
-                .load(Ordering::Relaxed)
-        } else {
-            iter_count
+        let needed_count = match self.touch_scanner(scan_id) {
+            Some(RedisScannerSlot::Keys(scanner)) => scanner.scanned_count.load(Ordering::Relaxed),
+            _ => iter_count,
         };
 
         self._invoke_new_scan(
+            scan_id,
             pattern,
             iter_count,
             // Add offset until reaching the maximum num.
             needed_count.saturating_add(offset.unwrap_or_default()),
             r#type,
+            regex,
         )
         .await?;
 
         // Continue scan.
-        let scanner = self.scanner.as_mut().unwrap();
-        scanner.signal.fetch_or(true, Ordering::Relaxed);
-
-        while let Some(result) = scanner.rx.recv().await {
-            return result;
-        }
+        let Some(RedisScannerSlot::Keys(scanner)) = self.touch_scanner(scan_id) else {
+            return Err(Error::FailedToGetRedisScanResult);
+        };
+        scanner.signal.notify_one();
 
-        Err(Error::FailedToGetRedisScanResult)
+        // Closed once the scanning task is cancelled.
+        scanner
+            .rx
+            .recv()
+            .await
+            .unwrap_or(Err(Error::RedisScanCancelled))
     }
 
-    async fn _invoke_new_hscan(
+    pub async fn hscan(
         &mut self,
+        scan_id: &ScanId,
         key: RedisKey,
         pattern: String,
         iter_count: u32,
         needed_count: u32,
-    ) -> Result<()> {
-        let (sx, rx) = unbounded_channel::<Result<RedisHScannerResult>>();
-
-        let signal = Arc::new(AtomicBool::new(true));
-        let scanned_count = Arc::new(AtomicU32::new(0));
-
-        let old = self.hscanner.replace(RedisHScanner {
-            rx,
-            key: key.clone(),
-            pattern: pattern.clone(),
-            iter_count,
-            signal: signal.clone(),
-            handler: None,
-            scanned_count: scanned_count.clone(),
-        });
-
-        drop(old);
+    ) -> Result<RedisHScannerResult> {
+        let iter_count = iter_count.max(needed_count);
 
-        let conn = self.conn()?;
-        let mut stream = conn.hscan(key, pattern, Some(iter_count)).boxed();
+        let reusable = matches!(
+            self.touch_scanner(scan_id),
+            Some(RedisScannerSlot::Hash(hscanner)) if hscanner.key == key
+                && hscanner.pattern == pattern
+                && hscanner.iter_count == iter_count
+        );
+        if !reusable {
+            self._invoke_new_hscan(scan_id, key, pattern, iter_count, needed_count)
+                .await?;
+        }
 
-        let signal = signal.clone();
-        let scanned_count = scanned_count.clone();
-        let old = self
-            .hscanner
-            .as_mut()
-            .unwrap()
-            .handler
-            .replace(tokio::spawn(async move {
-                loop {
-                    if signal.load(Ordering::Relaxed) {
-                        signal.fetch_and(false, Ordering::Relaxed);
-
-                        let mut sent = false;
-                        let mut hashmap = HashMap::with_capacity(iter_count as usize);
-                        while let Some(result) = stream.next().await {
-                            let result = result.map_err(Error::RedisInternalError);
-                            if result.is_err() {
-                                sx.send(Err(result.err().unwrap())).unwrap();
-                                sent = true;
-
-                                // End `next` loop.
-                                // Means we only request once.
-                                break;
-                            }
+        // Continue scan.
+        let Some(RedisScannerSlot::Hash(hscanner)) = self.touch_scanner(scan_id) else {
+            return Err(Error::FailedToGetRedisScanResult);
+        };
+        hscanner.signal.notify_one();
 
-                            let mut value = result.unwrap();
-                            let scanned_map = value.take_results();
-                            if scanned_map.is_some() {
-                                let scanned_map = scanned_map.unwrap().inner();
-                                scanned_map.into_iter().for_each(|(key, value)| {
-                                    hashmap.entry(key).or_insert(value);
-                                });
-                            }
+        // Closed once the scanning task is cancelled.
+        hscanner
+            .rx
+            .recv()
+            .await
+            .unwrap_or(Err(Error::RedisScanCancelled))
+    }
 
-                            // Record scanned count.
-                            // Use keys.len() to use the `.with_capacity` related functionality.
-                            scanned_count.fetch_add(hashmap.len() as u32, Ordering::Relaxed);
+    pub async fn force_new_hscan(
+        &mut self,
+        scan_id: &ScanId,
+        key: RedisKey,
+        pattern: String,
+        iter_count: u32,
+        needed_count: u32,
+    ) -> Result<RedisHScannerResult> {
+        self.close_scanner(scan_id);
 
-                            let can_continue = value.has_more();
-                            if can_continue {
-                                // Continue scanning.
-                                let _ = value.next();
-                            }
+        self.hscan(scan_id, key, pattern, iter_count, needed_count)
+            .await
+    }
 
-                            // If we scanned enough items
-                            // or cannot scan anymore.
-                            let scanned_count_raw = scanned_count.load(Ordering::Relaxed);
-                            let scanned_enough = scanned_count_raw >= needed_count || !can_continue;
-                            if scanned_enough {
-                                sx.send(Ok(RedisHScannerResult {
-                                    map: hashmap.try_into().unwrap(),
-                                    can_continue,
-                                }))
-                                .unwrap();
-                                sent = true;
-
-                                // End `next` loop.
-                                break;
-                            }
-                        }
+    pub async fn refresh_hscan(
+        &mut self,
+        scan_id: &ScanId,
+        key: RedisKey,
+        pattern: String,
+        iter_count: u32,
+        offset: Option<u32>,
+    ) -> Result<RedisHScannerResult> {
+        let needed_count = match self.touch_scanner(scan_id) {
+            Some(RedisScannerSlot::Hash(hscanner)) => {
+                hscanner.scanned_count.load(Ordering::Relaxed)
+            }
+            _ => iter_count,
+        };
 
-                        if !sent {
-                            sx.send(Ok(Default::default())).unwrap();
-                        }
-                    } else {
-                        tokio::time::sleep(Duration::from_millis(500)).await;
-                    }
-                }
-            }));
+        self._invoke_new_hscan(
+            scan_id,
+            key,
+            pattern,
+            iter_count,
+            // Add offset until reaching the maximum num.
+            needed_count.saturating_add(offset.unwrap_or_default()),
+        )
+        .await?;
 
-        drop(old);
+        // Continue scan.
+        let Some(RedisScannerSlot::Hash(hscanner)) = self.touch_scanner(scan_id) else {
+            return Err(Error::FailedToGetRedisScanResult);
+        };
+        hscanner.signal.notify_one();
 
-        Ok(())
+        // Closed once the scanning task is cancelled.
+        hscanner
+            .rx
+            .recv()
+            .await
+            .unwrap_or(Err(Error::RedisScanCancelled))
     }
 
-    pub async fn hscan(
+    pub async fn member_scan<M>(
         &mut self,
+        scan_id: &ScanId,
         key: RedisKey,
         pattern: String,
         iter_count: u32,
         needed_count: u32,
-    ) -> Result<RedisHScannerResult> {
+    ) -> Result<RedisMemberScannerResult<M>>
+    where
+        M: RedisScanMember,
+    {
         let iter_count = iter_count.max(needed_count);
 
-        if self.hscanner.is_some() {
-            let hscanner = self.hscanner.as_mut().unwrap();
-
-            if hscanner.pattern != pattern || hscanner.iter_count != iter_count {
-                self._invoke_new_hscan(key, pattern, iter_count, needed_count)
-                    .await?;
-            }
-        } else {
-            self._invoke_new_hscan(key, pattern, iter_count, needed_count)
+        let reusable = self
+            .touch_scanner(scan_id)
+            .and_then(M::as_scanner)
+            .is_some_and(|scanner| {
+                scanner.key == key && scanner.pattern == pattern && scanner.iter_count == iter_count
+            });
+        if !reusable {
+            self._invoke_new_member_scan::<M>(scan_id, key, pattern, iter_count, needed_count)
                 .await?;
         }
 
-        // Continue scan.
-        let hscanner = self.hscanner.as_mut().unwrap();
-        hscanner.signal.fetch_or(true, Ordering::Relaxed);
-
-        while let Some(result) = hscanner.rx.recv().await {
-            return result;
-        }
-
-        Err(Error::FailedToGetRedisScanResult)
+        self._receive_member_scan(scan_id).await
     }
 
-    pub async fn force_new_hscan(
+    pub async fn force_new_member_scan<M>(
         &mut self,
+        scan_id: &ScanId,
         key: RedisKey,
         pattern: String,
         iter_count: u32,
         needed_count: u32,
-    ) -> Result<RedisHScannerResult> {
-        let old = self.hscanner.take();
+    ) -> Result<RedisMemberScannerResult<M>>
+    where
+        M: RedisScanMember,
+    {
+        self.close_scanner(scan_id);
 
-        drop(old);
-
-        self.hscan(key, pattern, iter_count, needed_count).await
+        self.member_scan(scan_id, key, pattern, iter_count, needed_count)
+            .await
     }
 
-    pub async fn refresh_hscan(
+    pub async fn refresh_member_scan<M>(
         &mut self,
+        scan_id: &ScanId,
         key: RedisKey,
         pattern: String,
         iter_count: u32,
         offset: Option<u32>,
-    ) -> Result<RedisHScannerResult> {
-        let needed_count = if self.hscanner.is_some() {
-            self.hscanner
-                .as_ref()
-                .unwrap()
-                .scanned_count
-                .load(Ordering::Relaxed)
-        } else {
-            iter_count
-        };
-
-        self._invoke_new_hscan(
+    ) -> Result<RedisMemberScannerResult<M>>
+    where
+        M: RedisScanMember,
+    {
+        let needed_count = self
+            .touch_scanner(scan_id)
+            .and_then(M::as_scanner)
+            .map_or(iter_count, |scanner| {
+                scanner.scanned_count.load(Ordering::Relaxed)
+            });
+
+        self._invoke_new_member_scan::<M>(
+            scan_id,
             key,
             pattern,
             iter_count,
@@ -631,15 +1404,425 @@ impl RedisClient {
         )
         .await?;
 
+        self._receive_member_scan(scan_id).await
+    }
+
+    async fn _receive_member_scan<M>(
+        &mut self,
+        scan_id: &ScanId,
+    ) -> Result<RedisMemberScannerResult<M>>
+    where
+        M: RedisScanMember,
+    {
         // Continue scan.
-        let hscanner = self.hscanner.as_mut().unwrap();
-        hscanner.signal.fetch_or(true, Ordering::Relaxed);
+        let Some(scanner) = self.touch_scanner(scan_id).and_then(M::as_scanner) else {
+            return Err(Error::FailedToGetRedisScanResult);
+        };
+        scanner.signal.notify_one();
+
+        // Closed once the scanning task is cancelled.
+        scanner
+            .rx
+            .recv()
+            .await
+            .unwrap_or(Err(Error::RedisScanCancelled))
+    }
+
+    async fn new_scan_tracker(
+        &self,
+        scan_id: &ScanId,
+        kind: RedisScannerKind,
+        key: Option<&RedisKey>,
+    ) -> RedisScanProgressTracker {
+        // Only estimated when someone is watching.
+        let total = match (&self.scan_reporter, kind, key) {
+            (None, _, _) => None,
+            // DBSIZE only counts a single node of a cluster.
+            (_, RedisScannerKind::Keys, _) if self.manager.is_clustered() => None,
+            (_, RedisScannerKind::Keys, _) => self.manager.dbsize().await.ok(),
+            (_, RedisScannerKind::Hash, Some(key)) => self.manager.hlen(key.clone()).await.ok(),
+            (_, RedisScannerKind::Set, Some(key)) => self.manager.scard(key.clone()).await.ok(),
+            (_, RedisScannerKind::ZSet, Some(key)) => self.manager.zcard(key.clone()).await.ok(),
+            _ => None,
+        };
 
-        while let Some(result) = hscanner.rx.recv().await {
-            return result;
+        RedisScanProgressTracker {
+            progress: RedisScanProgress {
+                scan_id: scan_id.clone(),
+                kind,
+                scanned_count: 0,
+                iterations: 0,
+                total,
+                percent: total.map(|_| 0.0),
+                finished: false,
+            },
+            reporter: self.scan_reporter.clone(),
+            reported_at: Instant::now(),
         }
+    }
 
-        Err(Error::FailedToGetRedisScanResult)
+    async fn _invoke_new_scan(
+        &mut self,
+        scan_id: &ScanId,
+        pattern: String,
+        iter_count: u32,
+        needed_count: u32,
+        r#type: Option<ScanType>,
+        regex: Option<regex::bytes::Regex>,
+    ) -> Result<()> {
+        let (sx, rx) = unbounded_channel::<Result<RedisScannerResult>>();
+
+        // Scan the first page right away.
+        let signal = Arc::new(Notify::new());
+        signal.notify_one();
+        let scanned_count = Arc::new(AtomicU32::new(0));
+
+        // Stop the former one first.
+        self.close_scanner(scan_id);
+
+        let mut tracker = self
+            .new_scan_tracker(scan_id, RedisScannerKind::Keys, None)
+            .await;
+
+        let conn = &self.manager;
+        // Fan out to all the primaries of a cluster.
+        let clustered = conn.is_clustered();
+        let mut stream = if clustered {
+            conn.scan_cluster(pattern.clone(), Some(iter_count), r#type.clone())
+                .boxed()
+        } else {
+            conn.scan(pattern.clone(), Some(iter_count), r#type.clone())
+                .boxed()
+        }
+        .peekable();
+        let handler = {
+            let signal = signal.clone();
+            let scanned_count = scanned_count.clone();
+            let regex = regex.clone();
+
+            tokio::spawn(async move {
+                let mut needed_count = needed_count;
+
+                loop {
+                    signal.notified().await;
+
+                    let mut sent = false;
+                    let mut keys = Vec::with_capacity(needed_count as usize);
+                    while let Some(result) = stream.next().await {
+                        let result = result.map_err(Error::RedisInternalError);
+                        if result.is_err() {
+                            sx.send(Err(result.err().unwrap())).unwrap();
+                            sent = true;
+
+                            // End `next` loop.
+                            // Means we only request once.
+                            break;
+                        }
+
+                        let mut value = result.unwrap();
+                        let mut scanned_keys = value.take_results().unwrap_or_default();
+                        tracker.record(scanned_keys.len());
+                        if let Some(regex) = &regex {
+                            scanned_keys.retain(|key| regex.is_match(key.as_bytes()));
+                        }
+
+                        // Record the kept count of all the pages, which are scanned again on refresh.
+                        scanned_count.fetch_add(scanned_keys.len() as u32, Ordering::Relaxed);
+
+                        // Append
+                        keys.append(&mut scanned_keys);
+
+                        let has_more = value.has_more();
+                        if has_more {
+                            // Continue scanning.
+                            let _ = value.next();
+                        }
+
+                        // The cluster stream merges the pages of all the primaries,
+                        // only the end of the stream means there is nothing more.
+                        let can_continue =
+                            has_more || (clustered && Pin::new(&mut stream).peek().await.is_some());
+
+                        // If we kept enough keys for this page
+                        // or cannot scan anymore.
+                        let scanned_enough = keys.len() as u32 >= needed_count || !can_continue;
+                        if scanned_enough {
+                            sx.send(Ok(RedisScannerResult {
+                                keys: std::mem::take(&mut keys),
+                                can_continue,
+                            }))
+                            .unwrap();
+                            sent = true;
+                            tracker.report_page(can_continue);
+
+                            // End `next` loop.
+                            break;
+                        }
+                    }
+
+                    // The stream ended.
+                    if !sent {
+                        tracker.report_page(false);
+                        sx.send(Ok(RedisScannerResult {
+                            keys,
+                            can_continue: false,
+                        }))
+                        .unwrap();
+                    }
+
+                    // Only the first page refills the refreshed ones, the next ones are as large as the iteration.
+                    needed_count = iter_count;
+                }
+            })
+        };
+
+        self.insert_scanner(
+            scan_id,
+            RedisScannerSlot::Keys(RedisScanner {
+                rx,
+                pattern,
+                iter_count,
+                r#type,
+                regex,
+                signal,
+                handler: Some(handler),
+                scanned_count,
+            }),
+        );
+
+        Ok(())
+    }
+
+    async fn _invoke_new_hscan(
+        &mut self,
+        scan_id: &ScanId,
+        key: RedisKey,
+        pattern: String,
+        iter_count: u32,
+        needed_count: u32,
+    ) -> Result<()> {
+        let (sx, rx) = unbounded_channel::<Result<RedisHScannerResult>>();
+
+        // Scan the first page right away.
+        let signal = Arc::new(Notify::new());
+        signal.notify_one();
+        let scanned_count = Arc::new(AtomicU32::new(0));
+
+        // Stop the former one first.
+        self.close_scanner(scan_id);
+
+        let mut tracker = self
+            .new_scan_tracker(scan_id, RedisScannerKind::Hash, Some(&key))
+            .await;
+
+        let conn = &self.manager;
+        let mut stream = conn
+            .hscan(key.clone(), pattern.clone(), Some(iter_count))
+            .boxed();
+
+        let handler = {
+            let signal = signal.clone();
+            let scanned_count = scanned_count.clone();
+
+            tokio::spawn(async move {
+                let mut needed_count = needed_count;
+
+                loop {
+                    signal.notified().await;
+
+                    let mut sent = false;
+                    let mut hashmap = HashMap::with_capacity(needed_count as usize);
+                    while let Some(result) = stream.next().await {
+                        let result = result.map_err(Error::RedisInternalError);
+                        if result.is_err() {
+                            sx.send(Err(result.err().unwrap())).unwrap();
+                            sent = true;
+
+                            // End `next` loop.
+                            // Means we only request once.
+                            break;
+                        }
+
+                        let mut value = result.unwrap();
+                        let scanned_map = value.take_results();
+                        tracker.record(scanned_map.as_ref().map_or(0, RedisMap::len));
+                        if scanned_map.is_some() {
+                            let scanned_map = scanned_map.unwrap().inner();
+
+                            // Record the count of all the pages, which are scanned again on refresh.
+                            scanned_count.fetch_add(scanned_map.len() as u32, Ordering::Relaxed);
+
+                            scanned_map.into_iter().for_each(|(key, value)| {
+                                hashmap.entry(key).or_insert(value);
+                            });
+                        }
+
+                        let can_continue = value.has_more();
+                        if can_continue {
+                            // Continue scanning.
+                            let _ = value.next();
+                        }
+
+                        // If we scanned enough fields for this page
+                        // or cannot scan anymore.
+                        let scanned_enough = hashmap.len() as u32 >= needed_count || !can_continue;
+                        if scanned_enough {
+                            sx.send(Ok(RedisHScannerResult {
+                                map: hashmap.try_into().unwrap(),
+                                can_continue,
+                            }))
+                            .unwrap();
+                            sent = true;
+                            tracker.report_page(can_continue);
+
+                            // End `next` loop.
+                            break;
+                        }
+                    }
+
+                    if !sent {
+                        tracker.report_page(false);
+                        sx.send(Ok(Default::default())).unwrap();
+                    }
+
+                    // Only the first page refills the refreshed ones, the next ones are as large as the iteration.
+                    needed_count = iter_count;
+                }
+            })
+        };
+
+        self.insert_scanner(
+            scan_id,
+            RedisScannerSlot::Hash(RedisHScanner {
+                rx,
+                key,
+                pattern,
+                iter_count,
+                signal,
+                handler: Some(handler),
+                scanned_count,
+            }),
+        );
+
+        Ok(())
+    }
+
+    async fn _invoke_new_member_scan<M>(
+        &mut self,
+        scan_id: &ScanId,
+        key: RedisKey,
+        pattern: String,
+        iter_count: u32,
+        needed_count: u32,
+    ) -> Result<()>
+    where
+        M: RedisScanMember,
+    {
+        let (sx, rx) = unbounded_channel::<Result<RedisMemberScannerResult<M>>>();
+
+        // Scan the first page right away.
+        let signal = Arc::new(Notify::new());
+        signal.notify_one();
+        let scanned_count = Arc::new(AtomicU32::new(0));
+
+        // Stop the former one first.
+        self.close_scanner(scan_id);
+
+        let mut tracker = self.new_scan_tracker(scan_id, M::KIND, Some(&key)).await;
+
+        let conn = &self.manager;
+        let mut stream = M::scan(conn, key.clone(), pattern.clone(), iter_count);
+
+        let handler = {
+            let signal = signal.clone();
+            let scanned_count = scanned_count.clone();
+
+            tokio::spawn(async move {
+                // The server may return a member more than once.
+                let mut seen = HashSet::new();
+                let mut needed_count = needed_count;
+
+                loop {
+                    signal.notified().await;
+
+                    let mut sent = false;
+                    let mut members = Vec::with_capacity(needed_count as usize);
+                    while let Some(result) = stream.next().await {
+                        let result = result.map_err(Error::RedisInternalError);
+                        if result.is_err() {
+                            sx.send(Err(result.err().unwrap())).unwrap();
+                            sent = true;
+
+                            // End `next` loop.
+                            // Means we only request once.
+                            break;
+                        }
+
+                        let mut value = result.unwrap();
+                        let scanned_members =
+                            value.take_results().map_or_else(Vec::new, M::from_page);
+                        tracker.record(scanned_members.len());
+                        scanned_members.into_iter().for_each(|member| {
+                            if seen.insert(member.member().clone()) {
+                                members.push(member);
+                            }
+                        });
+
+                        // Record the count of all the pages, which are scanned again on refresh.
+                        scanned_count.store(seen.len() as u32, Ordering::Relaxed);
+
+                        let can_continue = value.has_more();
+                        if can_continue {
+                            // Continue scanning.
+                            let _ = value.next();
+                        }
+
+                        // If we scanned enough members for this page
+                        // or cannot scan anymore.
+                        let scanned_enough = members.len() as u32 >= needed_count || !can_continue;
+                        if scanned_enough {
+                            sx.send(Ok(RedisMemberScannerResult {
+                                members: std::mem::take(&mut members),
+                                can_continue,
+                            }))
+                            .unwrap();
+                            sent = true;
+                            tracker.report_page(can_continue);
+
+                            // End `next` loop.
+                            break;
+                        }
+                    }
+
+                    if !sent {
+                        tracker.report_page(false);
+                        sx.send(Ok(RedisMemberScannerResult {
+                            members: std::mem::take(&mut members),
+                            can_continue: false,
+                        }))
+                        .unwrap();
+                    }
+
+                    // Only the first page refills the refreshed ones, the next ones are as large as the iteration.
+                    needed_count = iter_count;
+                }
+            })
+        };
+
+        self.insert_scanner(
+            scan_id,
+            M::into_slot(RedisMemberScanner {
+                rx,
+                key,
+                pattern,
+                iter_count,
+                signal,
+                handler: Some(handler),
+                scanned_count,
+            }),
+        );
+
+        Ok(())
     }
 }
 
@@ -681,6 +1864,20 @@ impl RedisClientManager {
         lock.remove(index.unwrap());
     }
 
+    /// Aborts the running scan without waiting for the client,
+    /// so the scan holding the client returns at once.
+    pub fn cancel_scan(guid: &Guid, scan_id: &ScanId) -> bool {
+        let lock = RUNNING_REDIS_SCAN_TASKS.lock().unwrap();
+
+        match lock.get(&(guid.clone(), scan_id.clone())) {
+            Some(abort_handle) if !abort_handle.is_finished() => {
+                abort_handle.abort();
+                true
+            }
+            _ => false,
+        }
+    }
+
     pub async fn invoke_new_client(payload: RedisClientConnectionPayload) -> Result<RedisClient> {
         if RedisClientManager::judge_pending(&payload.guid) {
             return Err(Error::AlreadyAPendingRedisConnection);
@@ -719,31 +1916,55 @@ impl RedisClientManager {
     }
 
     pub async fn release_client(&mut self, guid: &Guid) -> Result<()> {
-        let found_client = self.get(guid);
-        let client = found_client.ok_or_else(|| Error::FailedToFindExistedRedisConnection)?;
+        let client = self
+            .remove(guid)
+            .ok_or_else(|| Error::FailedToFindExistedRedisConnection)?;
 
-        client
-            .manager
-            .quit()
-            .await
-            .map_err(Error::RedisInternalError)?;
-        self.remove(guid);
+        client.close().await
+    }
+}
 
-        Ok(())
+impl Drop for RedisScanTask {
+    fn drop(&mut self) {
+        let mut lock = RUNNING_REDIS_SCAN_TASKS.lock().unwrap();
+
+        // A newer scanner may be registered with the same id.
+        let replaced = lock
+            .get(&self.id)
+            .is_some_and(|abort_handle| !Arc::ptr_eq(abort_handle, &self.abort_handle));
+        if !replaced {
+            lock.remove(&self.id);
+        }
     }
 }
 
-impl Deref for RedisClient {
-    type Target = fred::clients::RedisClient;
+impl Drop for RedisScanner {
+    fn drop(&mut self) {
+        if let Some(handler) = self.handler.take() {
+            handler.abort();
+        }
+    }
+}
 
-    fn deref(&self) -> &Self::Target {
-        &self.manager
+impl Drop for RedisHScanner {
+    fn drop(&mut self) {
+        if let Some(handler) = self.handler.take() {
+            handler.abort();
+        }
     }
 }
 
-impl DerefMut for RedisClient {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.manager
+impl<M> Drop for RedisMemberScanner<M> {
+    fn drop(&mut self) {
+        if let Some(handler) = self.handler.take() {
+            handler.abort();
+        }
+    }
+}
+
+impl Drop for RedisClient {
+    fn drop(&mut self) {
+        self.stop_watching_state();
     }
 }
 
@@ -1,11 +1,163 @@
+use crate::features::bytes::RedisBytes;
+use crate::features::client::{RedisKeyType, RedisScannerKind};
 use serde::{Deserialize, Serialize};
+use tauri_redis_core::vault::secrets::Secret;
 
 type LocalTimeString = String;
 
 pub type Guid = String;
 
+// Supplied by the caller to tell the scanners apart.
+pub type ScanId = String;
+
 pub type TTL = i64;
 
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionAddress {
+    pub host: String,
+    pub port: u16,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ConnectionSentinelInfo {
+    pub hosts: Vec<ConnectionAddress>,
+    pub master_name: String,
+    // Auth of the sentinels themselves, the master uses the connection ones.
+    pub username: Option<String>,
+    pub password: Option<Secret>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ConnectionClusterInfo {
+    // Seed nodes, the connection host and port are used if empty.
+    pub hosts: Vec<ConnectionAddress>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ConnectionUnixInfo {
+    pub path: String,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ConnectionKind {
+    // Uses the connection host and port.
+    #[default]
+    Standalone,
+    Sentinel(ConnectionSentinelInfo),
+    Cluster(ConnectionClusterInfo),
+    // Not available on Windows.
+    Unix(ConnectionUnixInfo),
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ConnectionTlsInfo {
+    pub enabled: bool,
+    // PEM encoded CA bundle, appended to the system roots.
+    pub ca_file: Option<String>,
+    // PEM encoded client certificate and PKCS#8 private key, both needed for mutual TLS.
+    pub client_cert_file: Option<String>,
+    pub client_key_file: Option<String>,
+    // Only for the encrypted PKCS#8 keys, the legacy encrypted PEM keys are not supported.
+    pub client_key_passphrase: Option<Secret>,
+    // Overrides the server name used for SNI and certificate verification.
+    pub server_name: Option<String>,
+    // Only for development, accepts any certificate and hostname.
+    pub insecure_skip_verify: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ConnectionSshInfo {
+    pub enabled: bool,
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: Option<Secret>,
+    // Takes precedence over the password when provided.
+    pub private_key_file: Option<String>,
+    pub passphrase: Option<Secret>,
+    // SHA-256 of the server key, trusted on first use unless the host is in `known_hosts`.
+    pub host_key_fingerprint: Option<String>,
+}
+
+impl Default for ConnectionSshInfo {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: Default::default(),
+            port: 22,
+            username: Default::default(),
+            password: None,
+            private_key_file: None,
+            passphrase: None,
+            host_key_fingerprint: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ConnectionReconnectInfo {
+    pub enabled: bool,
+    // Zero means retrying forever.
+    pub max_attempts: u32,
+    // In milliseconds, the delay doubles after every failed attempt.
+    pub min_delay: u32,
+    pub max_delay: u32,
+}
+
+impl Default for ConnectionReconnectInfo {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_attempts: 10,
+            min_delay: 100,
+            max_delay: 10_000,
+        }
+    }
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ConnectionEnv {
+    #[default]
+    Untagged,
+    Dev,
+    Staging,
+    Prod,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionGroup {
+    pub name: String,
+    // Any css color.
+    pub color: Option<String>,
+
+    // Created by server.
+    pub created_at: LocalTimeString,
+    pub updated_at: Option<LocalTimeString>,
+
+    // Created by server.
+    pub guid: Guid,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveConnectionGroupPayload {
+    pub name: String,
+    pub color: Option<String>,
+
+    // Same as the `SaveConnectionPayload`.
+    pub guid: Option<Guid>,
+}
+
 #[derive(Default, Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ConnectionInfo {
@@ -13,9 +165,26 @@ pub struct ConnectionInfo {
     pub host: String,
     pub port: u16,
     pub username: String,
-    pub password: String,
+    // Left out by the webview when unchanged, the saved one is kept.
+    #[serde(default)]
+    pub password: Option<Secret>,
     pub separator: String,
     pub readonly: bool,
+    #[serde(default)]
+    pub db: u8,
+    #[serde(default)]
+    pub kind: ConnectionKind,
+    #[serde(default)]
+    pub tls: ConnectionTlsInfo,
+    #[serde(default)]
+    pub ssh: ConnectionSshInfo,
+    #[serde(default)]
+    pub reconnect: ConnectionReconnectInfo,
+    #[serde(default)]
+    pub env: ConnectionEnv,
+    // Ungrouped if none.
+    #[serde(default)]
+    pub group: Option<Guid>,
 
     // Created by server.
     pub created_at: LocalTimeString,
@@ -36,6 +205,12 @@ pub struct SaveConnectionPayload {
     pub password: Option<String>,
     pub separator: Option<String>,
     pub readonly: Option<bool>,
+    pub db: Option<u8>,
+    pub kind: Option<ConnectionKind>,
+    pub tls: Option<ConnectionTlsInfo>,
+    pub ssh: Option<ConnectionSshInfo>,
+    pub reconnect: Option<ConnectionReconnectInfo>,
+    pub env: Option<ConnectionEnv>,
 
     // If preset and matched, means edit.
     // Non preset means need a new one.
@@ -43,14 +218,364 @@ pub struct SaveConnectionPayload {
     pub guid: Option<Guid>,
 }
 
+// Content of the connections file.
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", from = "ConnectionsStoreFormat")]
+pub struct ConnectionsStore {
+    pub groups: Vec<ConnectionGroup>,
+    pub connections: Vec<ConnectionInfo>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ConnectionsStoreFormat {
+    // Saved by the former versions, before the groups.
+    Flat(Vec<ConnectionInfo>),
+    Grouped {
+        #[serde(default)]
+        groups: Vec<ConnectionGroup>,
+        #[serde(default)]
+        connections: Vec<ConnectionInfo>,
+    },
+}
+
+impl From<ConnectionsStoreFormat> for ConnectionsStore {
+    fn from(format: ConnectionsStoreFormat) -> Self {
+        match format {
+            ConnectionsStoreFormat::Flat(connections) => ConnectionsStore {
+                groups: vec![],
+                connections,
+            },
+            ConnectionsStoreFormat::Grouped {
+                groups,
+                connections,
+            } => ConnectionsStore {
+                groups,
+                connections,
+            },
+        }
+    }
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionsBundle {
+    pub version: u32,
+    pub exported_at: LocalTimeString,
+    pub connections: Vec<ConnectionInfo>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportConnectionsResult {
+    pub imported: Vec<Guid>,
+    // Names of the connections which already existed.
+    pub skipped: Vec<String>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ProtobufSchemaFileKind {
+    #[default]
+    Proto,
+    DescriptorSet,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtobufSchemaFile {
+    // The path used by the `import` statements.
+    pub name: String,
+    pub kind: ProtobufSchemaFileKind,
+    // The `.proto` source, or the base64 encoded descriptor set.
+    pub content: String,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtobufMessageMapping {
+    // Glob-style, same as the `KEYS` pattern.
+    pub pattern: String,
+    // The full name, e.g. `package.Message`.
+    pub message_type: String,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtobufSchemas {
+    pub files: Vec<ProtobufSchemaFile>,
+    // The first matched one wins.
+    pub mappings: Vec<ProtobufMessageMapping>,
+
+    // The connection guid.
+    pub guid: Guid,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionTestResult {
+    // In milliseconds, the round trip of PING.
+    pub latency: u64,
+    pub server_version: Option<String>,
+    // The authenticated user, unknown before Redis 6.
+    pub username: Option<String>,
+}
+
+// Only returned to the webview when explicitly requested.
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionSecrets {
+    pub password: Option<String>,
+    pub ssh_password: Option<String>,
+    pub ssh_passphrase: Option<String>,
+    pub sentinel_password: Option<String>,
+    pub tls_client_key_passphrase: Option<String>,
+}
+
 #[derive(Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Miscs {
     pub visited_page_routes: Vec<String>,
 }
 
+// A line of the `# Keyspace` section of INFO.
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisKeyspaceInfo {
+    pub db: u8,
+    pub keys: u64,
+    pub expires: u64,
+    // In milliseconds.
+    pub avg_ttl: u64,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisListPage {
+    pub items: Vec<RedisBytes>,
+    // LLEN of the key.
+    pub total: u64,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
-pub struct RedisHScanResultItem {
+pub struct RedisZSetMember {
+    pub member: RedisBytes,
+    pub score: f64,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisZSetPage {
+    pub items: Vec<RedisZSetMember>,
+    // ZCARD of the key.
+    pub total: u64,
+}
+
+// Bounds of ZRANGE BYSCORE/BYLEX, always from the lower one.
+#[derive(Debug, Deserialize)]
+pub struct RedisZSetRangeQuery {
+    pub min: String,
+    pub max: String,
+    pub offset: Option<i64>,
+    pub count: Option<i64>,
+    pub rev: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RedisStreamField {
+    pub name: RedisBytes,
+    pub value: RedisBytes,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RedisStreamEntry {
+    pub id: String,
+    // Keeps the order of XADD.
+    pub fields: Vec<RedisStreamField>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisStreamPage {
+    pub entries: Vec<RedisStreamEntry>,
+    // XLEN of the key.
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisStreamGroupInfo {
     pub name: String,
-    pub value: String,
+    pub consumers: u64,
+    pub pending: u64,
+    pub last_delivered_id: String,
+    // Since redis 7.0.
+    pub entries_read: Option<u64>,
+    pub lag: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisStreamConsumerInfo {
+    pub name: String,
+    pub pending: u64,
+    // In milliseconds.
+    pub idle: u64,
+    // Since redis 7.2.
+    pub inactive: Option<i64>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisStreamPendingSummary {
+    pub count: u64,
+    pub smallest_id: Option<String>,
+    pub greatest_id: Option<String>,
+    // The pending count of each consumer.
+    pub consumers: Vec<(String, u64)>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisStreamPendingEntry {
+    pub id: String,
+    pub consumer: String,
+    // In milliseconds.
+    pub idle: u64,
+    pub delivery_count: u64,
+}
+
+// The extended form of XPENDING.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisStreamPendingQuery {
+    pub start: Option<String>,
+    pub end: Option<String>,
+    pub count: u64,
+    pub consumer: Option<String>,
+    pub min_idle_time: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisStreamAutoClaimQuery {
+    pub consumer: String,
+    pub min_idle_time: u64,
+    pub start: Option<String>,
+    pub count: Option<u64>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisStreamAutoClaimResult {
+    // `0-0` if the whole PEL was scanned.
+    pub next_id: String,
+    pub entries: Vec<RedisStreamEntry>,
+    // The ids no longer in the stream, since redis 7.0.
+    pub deleted_ids: Vec<String>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+pub struct ValueFormatOptions {
+    pub format: Option<String>,
+    // `none` for the uncompressed values.
+    pub compression: Option<String>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct ValueFormatters {
+    pub formats: Vec<String>,
+    pub compressions: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum RedisKeyNamespaceNodeKind {
+    Folder,
+    Key,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisKeyNamespaceNode {
+    pub kind: RedisKeyNamespaceNodeKind,
+    // The segment after the expanded prefix.
+    pub name: RedisBytes,
+    // The prefix with the trailing separator for folders, which is used to expand them.
+    pub full_name: RedisBytes,
+    // The keys under a folder scanned so far by the same scanner, always 1 for keys.
+    pub count: u64,
+    // Only for keys.
+    pub key_type: Option<RedisKeyType>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisKeyNamespacePage {
+    // Only folded from the current page, the folders coming again carry the running counts.
+    pub nodes: Vec<RedisKeyNamespaceNode>,
+    pub can_continue: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RedisScanPatternMode {
+    // Matches the keys containing the input literally.
+    #[default]
+    Substring,
+    // The input is used as the glob pattern as is.
+    Exact,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisScanFilter {
+    #[serde(default)]
+    pub mode: RedisScanPatternMode,
+    // Only the keys of the type, filtered by the server.
+    pub key_type: Option<String>,
+    // Checked on the scanned keys, after the glob pattern.
+    pub regex: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisScannerOptions {
+    pub kind: RedisScannerKind,
+    // Required except for the keys.
+    pub key_name: Option<RedisBytes>,
+    // Glob-style, all by default.
+    pub pattern: Option<String>,
+    // The type and the regex only apply to the keys, kept for continuing.
+    pub filter: Option<RedisScanFilter>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum RedisScanItems {
+    Keys(Vec<RedisBytes>),
+    Hash(Vec<RedisHScanResultItem>),
+    Set(Vec<RedisBytes>),
+    ZSet(Vec<RedisZSetMember>),
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisScanPage {
+    pub kind: RedisScannerKind,
+    pub items: RedisScanItems,
+    pub can_continue: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RedisHashFormattedField {
+    pub name: RedisBytes,
+    // Encoded with the format options.
+    pub content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RedisHScanResultItem {
+    pub name: RedisBytes,
+    pub value: RedisBytes,
 }
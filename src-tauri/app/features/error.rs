@@ -33,6 +33,32 @@ pub enum Error {
     FailedToSaveConnectionInfo,
     #[error("Failed to find the matched connection info.")]
     FailedToFindTheMatchedConnectionInfo,
+    #[error("Failed to seal the connection secrets.")]
+    FailedToSealConnectionSecrets,
+    #[error("Failed to open the connection secrets.")]
+    FailedToOpenConnectionSecrets,
+    #[error("Failed to load the secrets vault.")]
+    FailedToLoadSecretsVault,
+    #[error("Failed to parse the connection group.")]
+    FailedToParseConnectionGroup,
+    #[error("Failed to find the matched connection group.")]
+    FailedToFindTheMatchedConnectionGroup,
+    #[error("Failed to get the cached protobuf schemas.")]
+    FailedToGetCachedProtobufSchemas,
+    #[error("Failed to parse the cached protobuf schemas.")]
+    FailedToParseCachedProtobufSchemas,
+    #[error("Failed to save the protobuf schemas.")]
+    FailedToSaveProtobufSchemas,
+    #[error("Invalid protobuf descriptor set.")]
+    InvalidProtobufDescriptorSet,
+    #[error("Failed to parse the connections bundle.")]
+    FailedToParseConnectionsBundle,
+    #[error("Unsupported connections bundle version.")]
+    UnsupportedConnectionsBundleVersion,
+    #[error("Invalid connection uri.")]
+    InvalidConnectionUri,
+    #[error("Only standalone connections can be presented as uri.")]
+    UnsupportedConnectionUriKind,
 
     #[error(transparent)]
     RedisInternalError(#[from] fred::error::RedisError),
@@ -44,10 +70,44 @@ pub enum Error {
     FailedToFindExistedRedisConnection,
     #[error("Failed to get redis scan result.")]
     FailedToGetRedisScanResult,
+    #[error("Failed to find the redis scanner.")]
+    FailedToFindRedisScanner,
+    #[error("The redis scan was cancelled.")]
+    RedisScanCancelled,
     #[error("Invalid redis key type.")]
     InvalidRedisKeyType,
     #[error("Invalid redis key name.")]
     InvalidRedisKeyName,
+    #[error("Invalid regex pattern.")]
+    InvalidRegexPattern,
+    #[error("Failed to parse the redis stream reply.")]
+    FailedToParseRedisStreamReply,
+    #[error("The connection is readonly.")]
+    ReadonlyConnection,
+    #[error("Failed to read the TLS certificate file.")]
+    FailedToReadTlsCertificateFile,
+    #[error("Invalid TLS certificate.")]
+    InvalidTlsCertificate,
+    #[error("Failed to decrypt the TLS client key.")]
+    FailedToDecryptTlsClientKey,
+    #[error("Failed to open the SSH tunnel.")]
+    FailedToOpenSshTunnel,
+    #[error("The SSH host key does not match the trusted one.")]
+    SshHostKeyMismatch,
+    #[error("SSH tunnel only supports standalone connections.")]
+    UnsupportedSshTunnelConnectionKind,
+    #[error("Invalid sentinel connection info.")]
+    InvalidSentinelConnectionInfo,
+    #[error("Not a sentinel connection.")]
+    NotASentinelConnection,
+    #[error("Failed to resolve the sentinel master.")]
+    FailedToResolveSentinelMaster,
+    #[error("Cluster connections only support database 0.")]
+    ClusterOnlySupportsDefaultDb,
+    #[error("Invalid unix socket path.")]
+    InvalidUnixSocketPath,
+    #[error("Unix socket connections are not supported on this platform.")]
+    UnsupportedUnixSocketConnection,
 
     #[error("Failed to get the cached miscs info.")]
     FailedToGetCachedMiscsInfo,
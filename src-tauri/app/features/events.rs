@@ -1,4 +1,6 @@
 #![allow(dead_code)]
+use crate::features::client::RedisScanProgress;
+use crate::features::command::Guid;
 use crate::features::error::Result;
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Event, EventHandler, Manager, Runtime};
@@ -9,10 +11,41 @@ pub struct EventsNonePayload;
 
 impl AsBackendEventPayload for EventsNonePayload {}
 
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum RedisConnectionState {
+    Connecting,
+    Connected,
+    Reconnecting,
+    Disconnected,
+    AuthFailed,
+}
+
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisConnectionStatePayload {
+    pub guid: Guid,
+    pub state: RedisConnectionState,
+}
+
+impl AsBackendEventPayload for RedisConnectionStatePayload {}
+
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisScanProgressPayload {
+    pub guid: Guid,
+    #[serde(flatten)]
+    pub progress: RedisScanProgress,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub enum Events {
     #[serde(rename = "internal:window:visible-changed-manually")]
     WindowVisibleChangedManually,
+    #[serde(rename = "redis:connection:state-changed")]
+    RedisConnectionStateChanged,
+    #[serde(rename = "redis:scan:progress")]
+    RedisScanProgressChanged,
 }
 
 impl ToString for Events {
@@ -69,4 +102,45 @@ impl Events {
     {
         Events::listen_with_type(handle, self.clone(), func)
     }
+
+    /// Notifies both the backend and the webview.
+    pub fn emit_redis_connection_state<R>(
+        handle: &AppHandle<R>,
+        guid: &Guid,
+        state: RedisConnectionState,
+    ) -> Result<()>
+    where
+        R: Runtime,
+    {
+        let payload = RedisConnectionStatePayload {
+            guid: guid.clone(),
+            state,
+        };
+
+        let event = Events::RedisConnectionStateChanged.to_string();
+        handle.trigger_global(&event, payload.as_payload());
+        handle.emit_all(&event, payload)?;
+
+        Ok(())
+    }
+
+    /// Notifies the webview only, the backend has no interest in it.
+    pub fn emit_redis_scan_progress<R>(
+        handle: &AppHandle<R>,
+        guid: &Guid,
+        progress: RedisScanProgress,
+    ) -> Result<()>
+    where
+        R: Runtime,
+    {
+        handle.emit_all(
+            &Events::RedisScanProgressChanged.to_string(),
+            RedisScanProgressPayload {
+                guid: guid.clone(),
+                progress,
+            },
+        )?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,108 @@
+use crate::features::command::{Guid, ProtobufSchemaFileKind, ProtobufSchemas, ValueFormatOptions};
+use crate::features::error::{Error, Result};
+use crate::utils::judgements::judge_key_matched;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use once_cell::sync::Lazy;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tauri_redis_core::formatter::protobuf::{ProtobufSchema, PROTOBUF_FORMAT};
+use tauri_redis_core::formatter::{FormattedValue, FormatterRegistry};
+
+pub type FormatterRegistryState = Arc<FormatterRegistry>;
+
+// Compiling is slow, so the compiled schemas are kept until the saved ones change.
+static COMPILED_PROTOBUF_SCHEMAS: Lazy<Arc<std::sync::Mutex<HashMap<Guid, ProtobufSchema>>>> =
+    Lazy::new(|| Arc::new(std::sync::Mutex::new(HashMap::new())));
+
+impl ValueFormatOptions {
+    /// The unspecified format and compression are detected.
+    pub fn decode(&self, registry: &FormatterRegistry, bytes: &[u8]) -> Result<FormattedValue> {
+        Ok(registry.decode(bytes, self.format.as_deref(), self.compression.as_deref())?)
+    }
+
+    /// The format should be specified, usually the detected one when decoding.
+    pub fn encode(&self, registry: &FormatterRegistry, content: &str) -> Result<Vec<u8>> {
+        let format = self.format.as_deref().ok_or(Error::InvalidParameter)?;
+
+        Ok(registry.encode(content, format, self.compression.as_deref())?)
+    }
+
+    /// Uses the protobuf message type mapped to the key, unless another format is specified.
+    pub fn with_protobuf_schemas<'a>(
+        &mut self,
+        registry: &'a FormatterRegistry,
+        schemas: Option<&ProtobufSchemas>,
+        key: &[u8],
+    ) -> Result<Cow<'a, FormatterRegistry>> {
+        if self
+            .format
+            .as_deref()
+            .is_some_and(|format| format != PROTOBUF_FORMAT)
+        {
+            return Ok(Cow::Borrowed(registry));
+        }
+
+        let (schemas, message_type) =
+            match schemas.and_then(|schemas| Some((schemas, schemas.find_message_type(key)?))) {
+                Some(found) => found,
+                None => return Ok(Cow::Borrowed(registry)),
+            };
+
+        let mut registry = registry.clone();
+        registry.register_formatter(schemas.compile_cached()?.formatter(message_type)?);
+        self.format = Some(PROTOBUF_FORMAT.to_string());
+
+        Ok(Cow::Owned(registry))
+    }
+}
+
+impl ProtobufSchemas {
+    pub fn compile(&self) -> Result<ProtobufSchema> {
+        let mut sources = vec![];
+        let mut descriptor_sets = vec![];
+        for file in &self.files {
+            match file.kind {
+                ProtobufSchemaFileKind::Proto => {
+                    sources.push((file.name.clone(), file.content.clone()))
+                }
+                ProtobufSchemaFileKind::DescriptorSet => descriptor_sets.push(
+                    BASE64
+                        .decode(&file.content)
+                        .map_err(|_| Error::InvalidProtobufDescriptorSet)?,
+                ),
+            }
+        }
+
+        Ok(ProtobufSchema::compile(sources, descriptor_sets)?)
+    }
+
+    /// Same as `compile`, but reuses the one compiled for the connection.
+    pub fn compile_cached(&self) -> Result<ProtobufSchema> {
+        if let Some(compiled) = COMPILED_PROTOBUF_SCHEMAS.lock().unwrap().get(&self.guid) {
+            return Ok(compiled.clone());
+        }
+
+        // Not to block the others while compiling.
+        let compiled = self.compile()?;
+        COMPILED_PROTOBUF_SCHEMAS
+            .lock()
+            .unwrap()
+            .insert(self.guid.clone(), compiled.clone());
+
+        Ok(compiled)
+    }
+
+    /// Should be called once the saved schemas of the connection changed.
+    pub fn invalidate_compiled(guid: &Guid) {
+        COMPILED_PROTOBUF_SCHEMAS.lock().unwrap().remove(guid);
+    }
+
+    pub fn find_message_type(&self, key: &[u8]) -> Option<&str> {
+        self.mappings
+            .iter()
+            .find(|mapping| judge_key_matched(mapping.pattern.as_bytes(), key))
+            .map(|mapping| mapping.message_type.as_str())
+    }
+}
@@ -11,3 +11,17 @@ pub mod response;
 pub mod context;
 
 pub mod events;
+
+pub mod secrets;
+
+pub mod uri;
+
+pub mod stream;
+
+pub mod bytes;
+
+pub mod formatter;
+
+pub mod namespace;
+
+pub mod readonly;
@@ -0,0 +1,155 @@
+use crate::features::bytes::RedisBytes;
+use crate::features::command::{RedisKeyNamespaceNode, RedisKeyNamespaceNodeKind};
+use fred::types::RedisKey;
+use std::collections::{BTreeMap, BTreeSet};
+
+impl RedisKeyNamespaceNode {
+    /// Folds the keys under the prefix by the next separator, folders go first.
+    ///
+    /// The keys not under the prefix are ignored, and all the keys are leaves if the separator is empty.
+    /// The folder counts only cover the given keys, i.e. the current page.
+    /// See `RedisClient::accumulate_folder_counts` for the running totals.
+    pub fn fold(keys: &[RedisKey], prefix: &[u8], separator: &[u8]) -> Vec<Self> {
+        let mut folders: BTreeMap<&[u8], u64> = BTreeMap::new();
+        let mut leaves: BTreeSet<&[u8]> = BTreeSet::new();
+
+        for key in keys {
+            let key = key.as_bytes();
+            let Some(rest) = key.strip_prefix(prefix) else {
+                continue;
+            };
+
+            let found = if separator.is_empty() {
+                None
+            } else {
+                rest.windows(separator.len())
+                    .position(|window| window == separator)
+            };
+
+            match found {
+                Some(idx) => {
+                    *folders
+                        .entry(&key[..prefix.len() + idx + separator.len()])
+                        .or_default() += 1;
+                }
+                None => {
+                    leaves.insert(key);
+                }
+            }
+        }
+
+        let folders = folders.into_iter().map(|(full_name, count)| Self {
+            kind: RedisKeyNamespaceNodeKind::Folder,
+            name: RedisBytes::from(
+                full_name[prefix.len()..full_name.len() - separator.len()].to_vec(),
+            ),
+            full_name: RedisBytes::from(full_name.to_vec()),
+            count,
+            key_type: None,
+        });
+        let leaves = leaves.into_iter().map(|full_name| Self {
+            kind: RedisKeyNamespaceNodeKind::Key,
+            name: RedisBytes::from(full_name[prefix.len()..].to_vec()),
+            full_name: RedisBytes::from(full_name.to_vec()),
+            count: 1,
+            key_type: None,
+        });
+
+        folders.chain(leaves).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fold(keys: &[&[u8]], prefix: &[u8], separator: &[u8]) -> Vec<RedisKeyNamespaceNode> {
+        let keys: Vec<RedisKey> = keys.iter().map(|key| RedisKey::from(*key)).collect();
+
+        RedisKeyNamespaceNode::fold(&keys, prefix, separator)
+    }
+
+    fn summary(nodes: &[RedisKeyNamespaceNode]) -> Vec<(bool, &[u8], &[u8], u64)> {
+        nodes
+            .iter()
+            .map(|node| {
+                (
+                    node.kind == RedisKeyNamespaceNodeKind::Folder,
+                    node.name.as_bytes(),
+                    node.full_name.as_bytes(),
+                    node.count,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn it_folds_the_folders_first() {
+        let nodes = fold(
+            &[b"user:2", b"user:1:name", b"post", b"user:1:age"],
+            b"",
+            b":",
+        );
+
+        assert_eq!(
+            summary(&nodes),
+            vec![
+                (true, &b"user"[..], &b"user:"[..], 3),
+                (false, &b"post"[..], &b"post"[..], 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_folds_under_the_prefix() {
+        let nodes = fold(
+            &[b"user:1:name", b"user:1:age", b"user:2", b"post:1"],
+            b"user:",
+            b":",
+        );
+
+        assert_eq!(
+            summary(&nodes),
+            vec![
+                (true, &b"1"[..], &b"user:1:"[..], 2),
+                (false, &b"2"[..], &b"user:2"[..], 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_folds_by_the_multi_byte_separator() {
+        let nodes = fold(&[b"a::b::c", b"a::b", b"a:b"], b"a::", b"::");
+
+        assert_eq!(
+            summary(&nodes),
+            vec![
+                (true, &b"b"[..], &b"a::b::"[..], 1),
+                (false, &b"b"[..], &b"a::b"[..], 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_keeps_the_leaves_with_the_empty_separator() {
+        let nodes = fold(&[b"a:b", b"a"], b"", b"");
+
+        assert_eq!(
+            summary(&nodes),
+            vec![
+                (false, &b"a"[..], &b"a"[..], 1),
+                (false, &b"a:b"[..], &b"a:b"[..], 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn it_folds_the_binary_keys() {
+        let nodes = fold(&[b"\xff:\x00:1", b"\xff:\x00:2", b"\xfe"], b"\xff:", b":");
+
+        assert_eq!(
+            summary(&nodes),
+            vec![(true, &b"\x00"[..], &b"\xff:\x00:"[..], 2)]
+        );
+    }
+}
@@ -0,0 +1,316 @@
+use crate::features::bytes::RedisBytes;
+use fred::bytes_utils::Str;
+use fred::clients::RedisClient;
+use fred::error::RedisError;
+use fred::interfaces::{
+    ClientLike, ClusterInterface, ConfigInterface, HashesInterface, KeysInterface, ListInterface,
+    RedisJsonInterface, SetsInterface, SortedSetsInterface, StreamsInterface,
+};
+use fred::prelude::RedisResult;
+use fred::types::{
+    CustomCommand, FromRedis, InfoKind, Limit, MultipleStrings, RedisConfig, RedisKey, RedisValue,
+    Server, XPendingArgs, ZRange, ZSort,
+};
+use fred::util::NONE;
+
+/// The connection handed out to the commands which only read.
+///
+/// Only the commands which never write are exposed, the others go through `writable_conn`,
+/// which refuses the readonly connections. A new command has to be added here to be read.
+pub struct RedisReadonlyConn<'a>(&'a RedisClient);
+
+impl<'a> RedisReadonlyConn<'a> {
+    pub(crate) fn new(client: &'a RedisClient) -> Self {
+        Self(client)
+    }
+
+    pub fn is_clustered(&self) -> bool {
+        self.0.is_clustered()
+    }
+
+    pub fn client_config(&self) -> RedisConfig {
+        self.0.client_config()
+    }
+
+    pub async fn active_connections(&self) -> RedisResult<Vec<Server>> {
+        self.0.active_connections().await
+    }
+
+    pub fn cluster_primaries(&self) -> Vec<Server> {
+        self.0
+            .cached_cluster_state()
+            .map(|state| state.unique_primary_nodes())
+            .unwrap_or_default()
+    }
+
+    /// Same as `info`, but only from the node of a cluster.
+    pub async fn cluster_node_info<R>(
+        &self,
+        node: Server,
+        section: Option<InfoKind>,
+    ) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        self.0.with_cluster_node(node).info(section).await
+    }
+
+    pub async fn ping<R>(&self) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        self.0.ping().await
+    }
+
+    pub async fn info<R>(&self, section: Option<InfoKind>) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        self.0.info(section).await
+    }
+
+    pub async fn config_get<R, S>(&self, parameter: S) -> RedisResult<R>
+    where
+        R: FromRedis,
+        S: Into<Str> + Send,
+    {
+        self.0.config_get(parameter).await
+    }
+
+    pub async fn acl_whoami<R>(&self) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        self.0
+            .custom(CustomCommand::new("ACL", None, false), vec!["WHOAMI"])
+            .await
+    }
+
+    pub async fn module_list<R>(&self) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        self.0
+            .custom(CustomCommand::new("MODULE", None, false), vec!["LIST"])
+            .await
+    }
+
+    pub async fn keys<R>(&self, pattern: String) -> RedisResult<R>
+    where
+        R: FromRedis,
+    {
+        self.0
+            .custom(CustomCommand::new("KEYS", None, false), vec![pattern])
+            .await
+    }
+
+    pub async fn key_type<R, K>(&self, key: K) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+    {
+        self.0
+            .custom(CustomCommand::new("TYPE", None, false), vec![key.into()])
+            .await
+    }
+
+    /// The types of the keys in one round trip.
+    pub async fn key_types(&self, keys: &[RedisBytes]) -> RedisResult<Vec<String>> {
+        let pipeline = self.0.pipeline();
+        for key in keys {
+            let _: () = pipeline
+                .custom(CustomCommand::new("TYPE", None, false), vec![key.clone()])
+                .await?;
+        }
+
+        pipeline.all().await
+    }
+
+    pub async fn ttl<R, K>(&self, key: K) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+    {
+        self.0.ttl(key).await
+    }
+
+    pub async fn get<R, K>(&self, key: K) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+    {
+        self.0.get(key).await
+    }
+
+    pub async fn hgetall<R, K>(&self, key: K) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+    {
+        self.0.hgetall(key).await
+    }
+
+    pub async fn hget<R, K, F>(&self, key: K, field: F) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+        F: Into<RedisKey> + Send,
+    {
+        self.0.hget(key, field).await
+    }
+
+    pub async fn llen<R, K>(&self, key: K) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+    {
+        self.0.llen(key).await
+    }
+
+    pub async fn lrange<R, K>(&self, key: K, start: i64, stop: i64) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+    {
+        self.0.lrange(key, start, stop).await
+    }
+
+    pub async fn scard<R, K>(&self, key: K) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+    {
+        self.0.scard(key).await
+    }
+
+    pub async fn sismember<R, K, V>(&self, key: K, member: V) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+        V: TryInto<RedisValue> + Send,
+        V::Error: Into<RedisError> + Send,
+    {
+        self.0.sismember(key, member).await
+    }
+
+    pub async fn zcard<R, K>(&self, key: K) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+    {
+        self.0.zcard(key).await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn zrange<R, K, M, N>(
+        &self,
+        key: K,
+        min: M,
+        max: N,
+        sort: Option<ZSort>,
+        rev: bool,
+        limit: Option<Limit>,
+        withscores: bool,
+    ) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+        M: TryInto<ZRange> + Send,
+        M::Error: Into<RedisError> + Send,
+        N: TryInto<ZRange> + Send,
+        N::Error: Into<RedisError> + Send,
+    {
+        self.0
+            .zrange(key, min, max, sort, rev, limit, withscores)
+            .await
+    }
+
+    pub async fn xlen<R, K>(&self, key: K) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+    {
+        self.0.xlen(key).await
+    }
+
+    pub async fn xrange<R, K, S, E>(
+        &self,
+        key: K,
+        start: S,
+        end: E,
+        count: Option<u64>,
+    ) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+        S: TryInto<RedisValue> + Send,
+        S::Error: Into<RedisError> + Send,
+        E: TryInto<RedisValue> + Send,
+        E::Error: Into<RedisError> + Send,
+    {
+        self.0.xrange(key, start, end, count).await
+    }
+
+    pub async fn xrevrange<R, K, E, S>(
+        &self,
+        key: K,
+        end: E,
+        start: S,
+        count: Option<u64>,
+    ) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+        S: TryInto<RedisValue> + Send,
+        S::Error: Into<RedisError> + Send,
+        E: TryInto<RedisValue> + Send,
+        E::Error: Into<RedisError> + Send,
+    {
+        self.0.xrevrange(key, end, start, count).await
+    }
+
+    pub async fn xinfo_groups<R, K>(&self, key: K) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+    {
+        self.0.xinfo_groups(key).await
+    }
+
+    pub async fn xinfo_consumers<R, K, S>(&self, key: K, group: S) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+        S: Into<Str> + Send,
+    {
+        self.0.xinfo_consumers(key, group).await
+    }
+
+    pub async fn xpending<R, K, G, A>(&self, key: K, group: G, args: A) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+        G: Into<Str> + Send,
+        A: Into<XPendingArgs> + Send,
+    {
+        self.0.xpending(key, group, args).await
+    }
+
+    pub async fn json_get<R, K, P>(&self, key: K, paths: P) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+        P: Into<MultipleStrings> + Send,
+    {
+        self.0.json_get(key, NONE, NONE, NONE, paths).await
+    }
+
+    pub async fn json_type<R, K, P>(&self, key: K, path: Option<P>) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: Into<RedisKey> + Send,
+        P: Into<Str> + Send,
+    {
+        self.0.json_type(key, path).await
+    }
+}
@@ -0,0 +1,106 @@
+use crate::features::command::{ConnectionInfo, ConnectionKind, ConnectionSecrets};
+use crate::features::error::{Error, Result};
+use std::sync::Arc;
+use tauri_redis_core::vault::secrets::{Secret, SecretsVault};
+
+pub type SecretsVaultState = Arc<SecretsVault>;
+
+impl ConnectionInfo {
+    fn map_secrets<F>(&mut self, mut f: F) -> Result<()>
+    where
+        F: FnMut(&Secret) -> Result<Secret>,
+    {
+        for secret in [
+            &mut self.password,
+            &mut self.ssh.password,
+            &mut self.ssh.passphrase,
+            &mut self.tls.client_key_passphrase,
+        ] {
+            if let Some(value) = secret.as_ref() {
+                secret.replace(f(value)?);
+            }
+        }
+
+        if let ConnectionKind::Sentinel(sentinel) = &mut self.kind {
+            if let Some(value) = sentinel.password.as_ref() {
+                sentinel.password.replace(f(value)?);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Encrypts the secrets before saving them to the disk.
+    pub fn seal_secrets(&mut self, vault: &SecretsVault) -> Result<()> {
+        self.map_secrets(|value| {
+            vault
+                .seal(value)
+                .map_err(|_| Error::FailedToSealConnectionSecrets)
+        })
+    }
+
+    pub fn open_secrets(&mut self, vault: &SecretsVault) -> Result<()> {
+        self.map_secrets(|value| {
+            vault
+                .open(value)
+                .map_err(|_| Error::FailedToOpenConnectionSecrets)
+        })
+    }
+
+    /// Removes the secrets before handing the info to the webview.
+    pub fn mask_secrets(&mut self) {
+        self.password = None;
+        self.ssh.password = None;
+        self.ssh.passphrase = None;
+        self.tls.client_key_passphrase = None;
+
+        if let ConnectionKind::Sentinel(sentinel) = &mut self.kind {
+            sentinel.password = None;
+        }
+    }
+
+    /// Keeps the previous secrets which were masked and not sent back by the webview.
+    pub fn inherit_secrets(&mut self, previous: &ConnectionInfo) {
+        if self.password.is_none() {
+            self.password = previous.password.clone();
+        }
+
+        if self.ssh.password.is_none() {
+            self.ssh.password = previous.ssh.password.clone();
+        }
+
+        if self.ssh.passphrase.is_none() {
+            self.ssh.passphrase = previous.ssh.passphrase.clone();
+        }
+
+        if self.tls.client_key_passphrase.is_none() {
+            self.tls.client_key_passphrase = previous.tls.client_key_passphrase.clone();
+        }
+
+        if let (ConnectionKind::Sentinel(sentinel), ConnectionKind::Sentinel(previous)) =
+            (&mut self.kind, &previous.kind)
+        {
+            if sentinel.password.is_none() {
+                sentinel.password = previous.password.clone();
+            }
+        }
+    }
+
+    pub fn secrets(&self) -> ConnectionSecrets {
+        ConnectionSecrets {
+            password: calc_plain(&self.password),
+            ssh_password: calc_plain(&self.ssh.password),
+            ssh_passphrase: calc_plain(&self.ssh.passphrase),
+            sentinel_password: match &self.kind {
+                ConnectionKind::Sentinel(sentinel) => calc_plain(&sentinel.password),
+                _ => None,
+            },
+            tls_client_key_passphrase: calc_plain(&self.tls.client_key_passphrase),
+        }
+    }
+}
+
+// The secrets are opened before being handed out.
+fn calc_plain(secret: &Option<Secret>) -> Option<String> {
+    secret.as_ref().and_then(Secret::plain).map(str::to_string)
+}
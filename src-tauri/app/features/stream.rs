@@ -0,0 +1,206 @@
+use crate::features::bytes::RedisBytes;
+use crate::features::command::{
+    RedisStreamAutoClaimResult, RedisStreamConsumerInfo, RedisStreamEntry, RedisStreamField,
+    RedisStreamGroupInfo, RedisStreamPendingEntry, RedisStreamPendingSummary,
+};
+use crate::features::error::{Error, Result};
+use fred::types::RedisValue;
+use std::collections::HashMap;
+
+fn calc_string(value: RedisValue) -> Result<String> {
+    value
+        .as_string()
+        .ok_or_else(|| Error::FailedToParseRedisStreamReply)
+}
+
+fn calc_bytes(value: RedisValue) -> Result<RedisBytes> {
+    RedisBytes::from_value(value).ok_or_else(|| Error::FailedToParseRedisStreamReply)
+}
+
+fn calc_optional_string(value: Option<RedisValue>) -> Option<String> {
+    value
+        .filter(|value| !value.is_null())
+        .and_then(|value| value.as_string())
+}
+
+// XINFO replies a flat array with RESP2 and a map with RESP3.
+fn calc_info_dict(value: RedisValue) -> Result<HashMap<String, RedisValue>> {
+    value
+        .convert::<HashMap<String, RedisValue>>()
+        .map_err(|_| Error::FailedToParseRedisStreamReply)
+}
+
+impl TryFrom<RedisValue> for RedisStreamEntry {
+    type Error = Error;
+
+    fn try_from(value: RedisValue) -> Result<Self> {
+        let mut parts = value.into_array().into_iter();
+
+        let id = calc_string(
+            parts
+                .next()
+                .ok_or_else(|| Error::FailedToParseRedisStreamReply)?,
+        )?;
+
+        // The fields are nil if the entry was deleted but still pending.
+        let mut fields = vec![];
+        let mut values = parts
+            .next()
+            .filter(|value| !value.is_null())
+            .map(|value| value.into_array())
+            .unwrap_or_default()
+            .into_iter();
+        while let (Some(name), Some(value)) = (values.next(), values.next()) {
+            fields.push(RedisStreamField {
+                name: calc_bytes(name)?,
+                value: calc_bytes(value)?,
+            });
+        }
+
+        Ok(Self { id, fields })
+    }
+}
+
+impl RedisStreamEntry {
+    pub fn from_values(value: RedisValue) -> Result<Vec<Self>> {
+        if value.is_null() {
+            return Ok(vec![]);
+        }
+
+        value.into_array().into_iter().map(Self::try_from).collect()
+    }
+}
+
+impl TryFrom<RedisValue> for RedisStreamGroupInfo {
+    type Error = Error;
+
+    fn try_from(value: RedisValue) -> Result<Self> {
+        let mut dict = calc_info_dict(value)?;
+
+        Ok(Self {
+            name: calc_optional_string(dict.remove("name"))
+                .ok_or_else(|| Error::FailedToParseRedisStreamReply)?,
+            consumers: dict
+                .remove("consumers")
+                .and_then(|v| v.as_u64())
+                .unwrap_or_default(),
+            pending: dict
+                .remove("pending")
+                .and_then(|v| v.as_u64())
+                .unwrap_or_default(),
+            last_delivered_id: calc_optional_string(dict.remove("last-delivered-id"))
+                .unwrap_or_default(),
+            entries_read: dict.remove("entries-read").and_then(|v| v.as_u64()),
+            lag: dict.remove("lag").and_then(|v| v.as_u64()),
+        })
+    }
+}
+
+impl TryFrom<RedisValue> for RedisStreamConsumerInfo {
+    type Error = Error;
+
+    fn try_from(value: RedisValue) -> Result<Self> {
+        let mut dict = calc_info_dict(value)?;
+
+        Ok(Self {
+            name: calc_optional_string(dict.remove("name"))
+                .ok_or_else(|| Error::FailedToParseRedisStreamReply)?,
+            pending: dict
+                .remove("pending")
+                .and_then(|v| v.as_u64())
+                .unwrap_or_default(),
+            idle: dict
+                .remove("idle")
+                .and_then(|v| v.as_u64())
+                .unwrap_or_default(),
+            inactive: dict.remove("inactive").and_then(|v| v.as_i64()),
+        })
+    }
+}
+
+impl TryFrom<RedisValue> for RedisStreamPendingSummary {
+    type Error = Error;
+
+    fn try_from(value: RedisValue) -> Result<Self> {
+        let mut parts = value.into_array().into_iter();
+
+        let count = parts
+            .next()
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| Error::FailedToParseRedisStreamReply)?;
+        let smallest_id = calc_optional_string(parts.next());
+        let greatest_id = calc_optional_string(parts.next());
+
+        let consumers = parts
+            .next()
+            .filter(|value| !value.is_null())
+            .map(|value| value.into_array())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|consumer| {
+                let mut consumer = consumer.into_array().into_iter();
+
+                let name = calc_optional_string(consumer.next())
+                    .ok_or_else(|| Error::FailedToParseRedisStreamReply)?;
+                // The count is replied as a bulk string.
+                let count = consumer.next().and_then(|v| v.as_u64()).unwrap_or_default();
+
+                Ok((name, count))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            count,
+            smallest_id,
+            greatest_id,
+            consumers,
+        })
+    }
+}
+
+impl TryFrom<RedisValue> for RedisStreamPendingEntry {
+    type Error = Error;
+
+    fn try_from(value: RedisValue) -> Result<Self> {
+        let mut parts = value.into_array().into_iter();
+
+        Ok(Self {
+            id: calc_optional_string(parts.next())
+                .ok_or_else(|| Error::FailedToParseRedisStreamReply)?,
+            consumer: calc_optional_string(parts.next())
+                .ok_or_else(|| Error::FailedToParseRedisStreamReply)?,
+            idle: parts.next().and_then(|v| v.as_u64()).unwrap_or_default(),
+            delivery_count: parts.next().and_then(|v| v.as_u64()).unwrap_or_default(),
+        })
+    }
+}
+
+impl TryFrom<RedisValue> for RedisStreamAutoClaimResult {
+    type Error = Error;
+
+    fn try_from(value: RedisValue) -> Result<Self> {
+        let mut parts = value.into_array().into_iter();
+
+        let next_id = calc_optional_string(parts.next())
+            .ok_or_else(|| Error::FailedToParseRedisStreamReply)?;
+        let entries = RedisStreamEntry::from_values(
+            parts
+                .next()
+                .ok_or_else(|| Error::FailedToParseRedisStreamReply)?,
+        )?;
+        let deleted_ids = parts
+            .next()
+            .filter(|value| !value.is_null())
+            .map(|value| value.into_array())
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|id| id.as_string())
+            .collect();
+
+        Ok(Self {
+            next_id,
+            entries,
+            deleted_ids,
+        })
+    }
+}
@@ -5,3 +5,73 @@ pub fn gen_uuid() -> String {
 pub fn get_cur_time() -> String {
     chrono::Local::now().to_rfc3339()
 }
+
+/// Escapes the glob special characters, so the text is matched literally.
+pub fn calc_glob_escaped(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if matches!(ch, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+
+    escaped
+}
+
+/// The pattern matching the keys starting with the prefix.
+///
+/// The patterns must be UTF-8, so the invalid bytes are replaced with `?`,
+/// the matched keys should be checked again with the prefix.
+pub fn calc_glob_prefix_pattern(mut prefix: &[u8]) -> String {
+    let mut pattern = String::with_capacity(prefix.len() + 1);
+    while !prefix.is_empty() {
+        match std::str::from_utf8(prefix) {
+            Ok(valid) => {
+                pattern.push_str(&calc_glob_escaped(valid));
+                break;
+            }
+            Err(err) => {
+                let (valid, invalid) = prefix.split_at(err.valid_up_to());
+                let invalid_len = err.error_len().unwrap_or(invalid.len());
+
+                pattern.push_str(&calc_glob_escaped(std::str::from_utf8(valid).unwrap()));
+                pattern.push_str(&"?".repeat(invalid_len));
+                prefix = &invalid[invalid_len..];
+            }
+        }
+    }
+    pattern.push('*');
+
+    pattern
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calc_glob_escaped, calc_glob_prefix_pattern};
+
+    #[test]
+    fn it_escapes_the_glob_characters() {
+        assert_eq!(calc_glob_escaped("user:1"), "user:1");
+        assert_eq!(calc_glob_escaped("*?[]\\"), "\\*\\?\\[\\]\\\\");
+        assert_eq!(calc_glob_escaped("a[b]*"), "a\\[b\\]\\*");
+        assert_eq!(calc_glob_escaped("键:*"), "键:\\*");
+    }
+
+    #[test]
+    fn it_calculates_the_prefix_pattern() {
+        assert_eq!(calc_glob_prefix_pattern(b""), "*");
+        assert_eq!(calc_glob_prefix_pattern(b"user:"), "user:*");
+        assert_eq!(calc_glob_prefix_pattern(b"a::b::"), "a::b::*");
+        assert_eq!(calc_glob_prefix_pattern(b"user[1]*:"), "user\\[1\\]\\*:*");
+        assert_eq!(calc_glob_prefix_pattern("键:".as_bytes()), "键:*");
+    }
+
+    #[test]
+    fn it_replaces_the_binary_prefix_bytes() {
+        assert_eq!(calc_glob_prefix_pattern(b"\xff:"), "?:*");
+        assert_eq!(calc_glob_prefix_pattern(b"a\xfe\xff*b"), "a??\\*b*");
+        // An incomplete multi-byte character at the end.
+        assert_eq!(calc_glob_prefix_pattern(b"a\xe9\x94"), "a??*");
+    }
+}
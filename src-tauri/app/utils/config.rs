@@ -11,12 +11,28 @@ pub fn get_connections_file_name() -> Result<String> {
         .map_err(Error::FailedToGetRelatedConfig)
 }
 
+pub fn get_protobuf_schemas_file_cache_manager_key() -> Result<String> {
+    CFG.get_string("protobuf_schemas.file_cache_manager_key")
+        .map_err(Error::FailedToGetRelatedConfig)
+}
+
+pub fn get_protobuf_schemas_file_name() -> Result<String> {
+    CFG.get_string("protobuf_schemas.filename")
+        .map_err(Error::FailedToGetRelatedConfig)
+}
+
 pub fn get_redis_connection_timeout() -> Result<u8> {
     CFG.get_int("redis.connection_timeout")
         .map(|num| num as u8)
         .map_err(Error::FailedToGetRelatedConfig)
 }
 
+pub fn get_redis_scanner_idle_timeout() -> Result<u64> {
+    CFG.get_int("redis.scanner_idle_timeout")
+        .map(|num| num as u64)
+        .map_err(Error::FailedToGetRelatedConfig)
+}
+
 pub fn get_redis_max_db_nums() -> Result<u8> {
     CFG.get_int("redis.max_db_nums")
         .map(|num| num as u8)
@@ -37,3 +53,8 @@ pub fn get_miscs_file_name() -> Result<String> {
     CFG.get_string("miscs.filename")
         .map_err(Error::FailedToGetRelatedConfig)
 }
+
+pub fn get_secrets_key_file_name() -> Result<String> {
+    CFG.get_string("secrets.key_filename")
+        .map_err(Error::FailedToGetRelatedConfig)
+}
@@ -12,3 +12,151 @@ pub fn judge_guid_valid(guid: &Guid) -> Result<bool> {
 
     Ok(true)
 }
+
+// Returns whether the class matched and the pattern after the closing `]`.
+fn judge_class_matched(pattern: &[u8], ch: u8) -> (bool, &[u8]) {
+    let (negated, mut pattern) = match pattern.split_first() {
+        Some((b'^', rest)) => (true, rest),
+        _ => (false, pattern),
+    };
+
+    let mut matched = false;
+    loop {
+        match pattern {
+            // Unclosed, same as redis.
+            [] => break,
+            [b']', rest @ ..] => {
+                pattern = rest;
+                break;
+            }
+            [b'\\', escaped, rest @ ..] => {
+                matched |= *escaped == ch;
+                pattern = rest;
+            }
+            [start, b'-', end, rest @ ..] if *end != b']' => {
+                matched |= (*start.min(end)..=*start.max(end)).contains(&ch);
+                pattern = rest;
+            }
+            [other, rest @ ..] => {
+                matched |= *other == ch;
+                pattern = rest;
+            }
+        }
+    }
+
+    (matched != negated, pattern)
+}
+
+/// Glob-style matching the same as the redis `KEYS` pattern, e.g. `user:*`, `h?llo`, `h[^e]llo`.
+///
+/// Only the last `*` is backtracked to, so it stays linear in the key for each star.
+pub fn judge_key_matched(pattern: &[u8], key: &[u8]) -> bool {
+    let (mut pattern_idx, mut key_idx) = (0, 0);
+    // The pattern after the last `*` and the key position it has swallowed up to.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while key_idx < key.len() {
+        let ch = key[key_idx];
+        let consumed = match &pattern[pattern_idx..] {
+            [b'*', ..] => {
+                pattern_idx += 1;
+                backtrack = Some((pattern_idx, key_idx));
+                continue;
+            }
+            [b'?', ..] => Some(1),
+            [b'[', rest @ ..] => {
+                let (matched, rest) = judge_class_matched(rest, ch);
+
+                matched.then(|| pattern.len() - pattern_idx - rest.len())
+            }
+            [b'\\', escaped, ..] => (*escaped == ch).then_some(2),
+            [expected, ..] => (*expected == ch).then_some(1),
+            [] => None,
+        };
+
+        match (consumed, backtrack) {
+            (Some(consumed), _) => {
+                pattern_idx += consumed;
+                key_idx += 1;
+            }
+            // Let the last `*` swallow one more byte.
+            (None, Some((star_pattern_idx, star_key_idx))) => {
+                backtrack = Some((star_pattern_idx, star_key_idx + 1));
+                pattern_idx = star_pattern_idx;
+                key_idx = star_key_idx + 1;
+            }
+            (None, None) => return false,
+        }
+    }
+
+    pattern[pattern_idx..].iter().all(|ch| *ch == b'*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::judge_key_matched;
+
+    fn matched(pattern: &str, key: &str) -> bool {
+        judge_key_matched(pattern.as_bytes(), key.as_bytes())
+    }
+
+    #[test]
+    fn it_matches_the_wildcards() {
+        assert!(matched("*", ""));
+        assert!(matched("user:*", "user:1"));
+        assert!(matched("*:1", "user:1"));
+        assert!(matched("u*r*1", "user:1"));
+        assert!(matched("**", "user"));
+        assert!(!matched("user:*", "users"));
+
+        assert!(matched("h?llo", "hello"));
+        assert!(!matched("h?llo", "hllo"));
+        assert!(matched("*?", "a"));
+        assert!(!matched("*?", ""));
+    }
+
+    #[test]
+    fn it_matches_the_classes() {
+        assert!(matched("h[ae]llo", "hallo"));
+        assert!(!matched("h[ae]llo", "hillo"));
+        assert!(matched("h[^e]llo", "hallo"));
+        assert!(!matched("h[^e]llo", "hello"));
+
+        // Ranges, either way round.
+        assert!(matched("key[0-9]", "key5"));
+        assert!(matched("key[9-0]", "key5"));
+        assert!(!matched("key[0-9]", "keya"));
+        assert!(matched("key[^0-9]", "keya"));
+        // A trailing `-` is a literal one.
+        assert!(matched("key[a-]", "key-"));
+
+        // Escaped inside a class.
+        assert!(matched("key[\\]]", "key]"));
+    }
+
+    #[test]
+    fn it_matches_the_escapes() {
+        assert!(matched("key\\*", "key*"));
+        assert!(!matched("key\\*", "key1"));
+        assert!(matched("key\\?", "key?"));
+        assert!(matched("key\\[1]", "key[1]"));
+        assert!(matched("\\\\", "\\"));
+    }
+
+    #[test]
+    fn it_matches_an_unclosed_class_till_the_end() {
+        assert!(matched("key[abc", "keya"));
+        assert!(!matched("key[abc", "keyd"));
+        // Nothing left to match after the class.
+        assert!(!matched("key[abc", "keyab"));
+    }
+
+    #[test]
+    fn it_stays_linear_with_many_stars() {
+        let pattern = "*a".repeat(32) + "b";
+        let key = "a".repeat(4096);
+
+        assert!(!matched(&pattern, &key));
+        assert!(matched(&pattern, &(key + "b")));
+    }
+}
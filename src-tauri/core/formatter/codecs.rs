@@ -0,0 +1,146 @@
+use crate::features::error::AnyError;
+use crate::formatter::ValueFormatter;
+use serde_json::Value;
+
+pub const RAW_FORMAT: &str = "raw";
+pub const HEX_FORMAT: &str = "hex";
+pub const JSON_FORMAT: &str = "json";
+pub const MESSAGE_PACK_FORMAT: &str = "msgpack";
+pub const CBOR_FORMAT: &str = "cbor";
+
+fn to_pretty_json(value: &Value) -> Result<String, AnyError> {
+    Ok(serde_json::to_string_pretty(value)?)
+}
+
+/// UTF-8 texts as is.
+pub struct RawFormatter;
+
+impl ValueFormatter for RawFormatter {
+    fn name(&self) -> &str {
+        RAW_FORMAT
+    }
+
+    fn detect(&self, bytes: &[u8]) -> bool {
+        std::str::from_utf8(bytes).is_ok()
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<String, AnyError> {
+        Ok(std::str::from_utf8(bytes)?.to_string())
+    }
+
+    fn encode(&self, content: &str) -> Result<Vec<u8>, AnyError> {
+        Ok(content.as_bytes().to_vec())
+    }
+}
+
+/// Lowercase hex digits, the whitespaces are ignored when encoding.
+pub struct HexFormatter;
+
+impl ValueFormatter for HexFormatter {
+    fn name(&self) -> &str {
+        HEX_FORMAT
+    }
+
+    fn detect(&self, _bytes: &[u8]) -> bool {
+        true
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<String, AnyError> {
+        Ok(hex::encode(bytes))
+    }
+
+    fn encode(&self, content: &str) -> Result<Vec<u8>, AnyError> {
+        let digits: String = content.split_whitespace().collect();
+
+        Ok(hex::decode(digits)?)
+    }
+}
+
+/// Pretty printed when decoding, compacted when encoding.
+pub struct JsonFormatter;
+
+impl ValueFormatter for JsonFormatter {
+    fn name(&self) -> &str {
+        JSON_FORMAT
+    }
+
+    // Bare strings and numbers are treated as raw texts.
+    fn detect(&self, bytes: &[u8]) -> bool {
+        matches!(
+            bytes.iter().find(|b| !b.is_ascii_whitespace()),
+            Some(b'{') | Some(b'[')
+        )
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<String, AnyError> {
+        to_pretty_json(&serde_json::from_slice::<Value>(bytes)?)
+    }
+
+    fn encode(&self, content: &str) -> Result<Vec<u8>, AnyError> {
+        Ok(serde_json::to_vec(&serde_json::from_str::<Value>(
+            content,
+        )?)?)
+    }
+}
+
+/// Presented as JSON.
+pub struct MessagePackFormatter;
+
+impl ValueFormatter for MessagePackFormatter {
+    fn name(&self) -> &str {
+        MESSAGE_PACK_FORMAT
+    }
+
+    // Only the maps and arrays, others are hardly told from the raw bytes.
+    fn detect(&self, bytes: &[u8]) -> bool {
+        matches!(bytes.first(), Some(0x80..=0x9f) | Some(0xdc..=0xdf))
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<String, AnyError> {
+        let mut reader = bytes;
+        let value: Value = rmp_serde::from_read(&mut reader)?;
+
+        // Trailing bytes mean it is not a single MessagePack value.
+        if !reader.is_empty() {
+            return Err(AnyError::msg("Unexpected trailing bytes of MessagePack."));
+        }
+
+        to_pretty_json(&value)
+    }
+
+    fn encode(&self, content: &str) -> Result<Vec<u8>, AnyError> {
+        Ok(rmp_serde::to_vec(&serde_json::from_str::<Value>(content)?)?)
+    }
+}
+
+/// Presented as JSON.
+pub struct CborFormatter;
+
+impl ValueFormatter for CborFormatter {
+    fn name(&self) -> &str {
+        CBOR_FORMAT
+    }
+
+    // Maps, arrays and the self-described tag.
+    fn detect(&self, bytes: &[u8]) -> bool {
+        bytes.starts_with(&[0xd9, 0xd9, 0xf7]) || matches!(bytes.first(), Some(0x80..=0xbf))
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<String, AnyError> {
+        let mut reader = bytes;
+        let value: Value = ciborium::from_reader(&mut reader)?;
+
+        if !reader.is_empty() {
+            return Err(AnyError::msg("Unexpected trailing bytes of CBOR."));
+        }
+
+        to_pretty_json(&value)
+    }
+
+    fn encode(&self, content: &str) -> Result<Vec<u8>, AnyError> {
+        let mut bytes = vec![];
+        ciborium::into_writer(&serde_json::from_str::<Value>(content)?, &mut bytes)?;
+
+        Ok(bytes)
+    }
+}
@@ -0,0 +1,144 @@
+use crate::features::error::AnyError;
+use crate::formatter::ValueCompressor;
+use std::io::{Read, Write};
+
+// Guards against the decompression bombs.
+const MAX_DECOMPRESSED_LEN: u64 = 64 * 1024 * 1024;
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+const LZ4_FRAME_MAGIC: &[u8] = &[0x04, 0x22, 0x4d, 0x18];
+const SNAPPY_FRAME_MAGIC: &[u8] = &[0xff, 0x06, 0x00, 0x00, 0x73, 0x4e, 0x61, 0x50, 0x70, 0x59];
+
+fn read_limited<R: Read>(reader: R) -> Result<Vec<u8>, AnyError> {
+    let mut bytes = vec![];
+    reader
+        .take(MAX_DECOMPRESSED_LEN + 1)
+        .read_to_end(&mut bytes)?;
+
+    if bytes.len() as u64 > MAX_DECOMPRESSED_LEN {
+        return Err(AnyError::msg("The decompressed value is too large."));
+    }
+
+    Ok(bytes)
+}
+
+pub struct GzipCompressor;
+
+impl ValueCompressor for GzipCompressor {
+    fn name(&self) -> &str {
+        "gzip"
+    }
+
+    fn detect(&self, bytes: &[u8]) -> bool {
+        bytes.starts_with(GZIP_MAGIC)
+    }
+
+    fn decompress(&self, bytes: &[u8]) -> Result<Vec<u8>, AnyError> {
+        read_limited(flate2::read::GzDecoder::new(bytes))
+    }
+
+    fn compress(&self, bytes: &[u8]) -> Result<Vec<u8>, AnyError> {
+        let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+        encoder.write_all(bytes)?;
+
+        Ok(encoder.finish()?)
+    }
+}
+
+pub struct ZlibCompressor;
+
+impl ValueCompressor for ZlibCompressor {
+    fn name(&self) -> &str {
+        "zlib"
+    }
+
+    // CMF of deflate with the header checksum.
+    fn detect(&self, bytes: &[u8]) -> bool {
+        match bytes {
+            [cmf, flg, ..] => cmf & 0x0f == 8 && (u16::from(*cmf) << 8 | u16::from(*flg)) % 31 == 0,
+            _ => false,
+        }
+    }
+
+    fn decompress(&self, bytes: &[u8]) -> Result<Vec<u8>, AnyError> {
+        read_limited(flate2::read::ZlibDecoder::new(bytes))
+    }
+
+    fn compress(&self, bytes: &[u8]) -> Result<Vec<u8>, AnyError> {
+        let mut encoder = flate2::write::ZlibEncoder::new(vec![], flate2::Compression::default());
+        encoder.write_all(bytes)?;
+
+        Ok(encoder.finish()?)
+    }
+}
+
+pub struct ZstdCompressor;
+
+impl ValueCompressor for ZstdCompressor {
+    fn name(&self) -> &str {
+        "zstd"
+    }
+
+    fn detect(&self, bytes: &[u8]) -> bool {
+        bytes.starts_with(ZSTD_MAGIC)
+    }
+
+    fn decompress(&self, bytes: &[u8]) -> Result<Vec<u8>, AnyError> {
+        read_limited(zstd::stream::read::Decoder::new(bytes)?)
+    }
+
+    fn compress(&self, bytes: &[u8]) -> Result<Vec<u8>, AnyError> {
+        Ok(zstd::stream::encode_all(bytes, 0)?)
+    }
+}
+
+/// The frame format, the raw blocks have no magic bytes to detect.
+pub struct Lz4Compressor;
+
+impl ValueCompressor for Lz4Compressor {
+    fn name(&self) -> &str {
+        "lz4"
+    }
+
+    fn detect(&self, bytes: &[u8]) -> bool {
+        bytes.starts_with(LZ4_FRAME_MAGIC)
+    }
+
+    fn decompress(&self, bytes: &[u8]) -> Result<Vec<u8>, AnyError> {
+        read_limited(lz4_flex::frame::FrameDecoder::new(bytes))
+    }
+
+    fn compress(&self, bytes: &[u8]) -> Result<Vec<u8>, AnyError> {
+        let mut encoder = lz4_flex::frame::FrameEncoder::new(vec![]);
+        encoder.write_all(bytes)?;
+
+        Ok(encoder.finish()?)
+    }
+}
+
+/// The framing format, the raw blocks have no magic bytes to detect.
+pub struct SnappyCompressor;
+
+impl ValueCompressor for SnappyCompressor {
+    fn name(&self) -> &str {
+        "snappy"
+    }
+
+    fn detect(&self, bytes: &[u8]) -> bool {
+        bytes.starts_with(SNAPPY_FRAME_MAGIC)
+    }
+
+    fn decompress(&self, bytes: &[u8]) -> Result<Vec<u8>, AnyError> {
+        read_limited(snap::read::FrameDecoder::new(bytes))
+    }
+
+    fn compress(&self, bytes: &[u8]) -> Result<Vec<u8>, AnyError> {
+        let mut encoder = snap::write::FrameEncoder::new(vec![]);
+        encoder.write_all(bytes)?;
+
+        encoder
+            .into_inner()
+            .map_err(|err| AnyError::msg(err.to_string()))
+    }
+}
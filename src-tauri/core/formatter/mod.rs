@@ -0,0 +1,230 @@
+pub mod codecs;
+
+pub mod compressors;
+
+pub mod protobuf;
+
+use crate::features::error::AnyError;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Stands for the values stored without compression.
+pub const NO_COMPRESSION: &str = "none";
+
+/// Decodes the (decompressed) bytes to a readable text, and encodes the edited text back.
+pub trait ValueFormatter: Send + Sync {
+    fn name(&self) -> &str;
+    /// Whether the bytes look like this format, used by the auto detection.
+    fn detect(&self, bytes: &[u8]) -> bool;
+    fn decode(&self, bytes: &[u8]) -> Result<String, AnyError>;
+    fn encode(&self, content: &str) -> Result<Vec<u8>, AnyError>;
+}
+
+pub trait ValueCompressor: Send + Sync {
+    fn name(&self) -> &str;
+    /// Usually by the magic bytes.
+    fn detect(&self, bytes: &[u8]) -> bool;
+    fn decompress(&self, bytes: &[u8]) -> Result<Vec<u8>, AnyError>;
+    fn compress(&self, bytes: &[u8]) -> Result<Vec<u8>, AnyError>;
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FormattedValue {
+    pub format: String,
+    pub compression: String,
+    pub content: String,
+}
+
+/// Formatters and compressors are tried in the registered order when detecting.
+#[derive(Default, Clone)]
+pub struct FormatterRegistry {
+    formatters: Vec<Arc<dyn ValueFormatter>>,
+    compressors: Vec<Arc<dyn ValueCompressor>>,
+}
+
+impl FormatterRegistry {
+    /// With the built-in formatters and compressors.
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::default();
+
+        registry
+            .register_compressor(compressors::GzipCompressor)
+            .register_compressor(compressors::ZlibCompressor)
+            .register_compressor(compressors::ZstdCompressor)
+            .register_compressor(compressors::Lz4Compressor)
+            .register_compressor(compressors::SnappyCompressor);
+
+        // Raw and hex are the fallbacks, keep them at the end.
+        registry
+            .register_formatter(codecs::JsonFormatter)
+            .register_formatter(codecs::MessagePackFormatter)
+            .register_formatter(codecs::CborFormatter)
+            .register_formatter(codecs::RawFormatter)
+            .register_formatter(codecs::HexFormatter);
+
+        registry
+    }
+
+    /// Registered before the fallback ones, so they are detected first.
+    pub fn register_formatter<F: ValueFormatter + 'static>(&mut self, formatter: F) -> &mut Self {
+        let is_fallback = |name: &str| [codecs::RAW_FORMAT, codecs::HEX_FORMAT].contains(&name);
+
+        let idx = if is_fallback(formatter.name()) {
+            self.formatters.len()
+        } else {
+            self.formatters
+                .iter()
+                .position(|f| is_fallback(f.name()))
+                .unwrap_or(self.formatters.len())
+        };
+        self.formatters.insert(idx, Arc::new(formatter));
+
+        self
+    }
+
+    pub fn register_compressor<C: ValueCompressor + 'static>(
+        &mut self,
+        compressor: C,
+    ) -> &mut Self {
+        self.compressors.push(Arc::new(compressor));
+
+        self
+    }
+
+    pub fn formatters(&self) -> Vec<String> {
+        self.formatters
+            .iter()
+            .map(|f| f.name().to_string())
+            .collect()
+    }
+
+    pub fn compressors(&self) -> Vec<String> {
+        self.compressors
+            .iter()
+            .map(|c| c.name().to_string())
+            .collect()
+    }
+
+    fn find_formatter(&self, name: &str) -> Result<&Arc<dyn ValueFormatter>, AnyError> {
+        self.formatters
+            .iter()
+            .find(|f| f.name() == name)
+            .ok_or_else(|| AnyError::msg(format!("Unknown value format: {}.", name)))
+    }
+
+    fn find_compressor(&self, name: &str) -> Result<Option<&Arc<dyn ValueCompressor>>, AnyError> {
+        if name == NO_COMPRESSION {
+            return Ok(None);
+        }
+
+        self.compressors
+            .iter()
+            .find(|c| c.name() == name)
+            .map(Some)
+            .ok_or_else(|| AnyError::msg(format!("Unknown value compression: {}.", name)))
+    }
+
+    /// Detects the format and compression if they are not specified.
+    pub fn decode(
+        &self,
+        bytes: &[u8],
+        format: Option<&str>,
+        compression: Option<&str>,
+    ) -> Result<FormattedValue, AnyError> {
+        let (compression, bytes) = match compression {
+            Some(name) => match self.find_compressor(name)? {
+                Some(compressor) => (name.to_string(), compressor.decompress(bytes)?),
+                None => (NO_COMPRESSION.to_string(), bytes.to_vec()),
+            },
+            None => self
+                .compressors
+                .iter()
+                .filter(|c| c.detect(bytes))
+                .find_map(|c| {
+                    c.decompress(bytes)
+                        .ok()
+                        .map(|decompressed| (c.name().to_string(), decompressed))
+                })
+                .unwrap_or_else(|| (NO_COMPRESSION.to_string(), bytes.to_vec())),
+        };
+
+        let (format, content) = match format {
+            Some(name) => (name.to_string(), self.find_formatter(name)?.decode(&bytes)?),
+            None => self
+                .formatters
+                .iter()
+                .filter(|f| f.detect(&bytes))
+                .find_map(|f| {
+                    f.decode(&bytes)
+                        .ok()
+                        .map(|content| (f.name().to_string(), content))
+                })
+                .ok_or_else(|| AnyError::msg("Failed to detect the value format."))?,
+        };
+
+        Ok(FormattedValue {
+            format,
+            compression,
+            content,
+        })
+    }
+
+    pub fn encode(
+        &self,
+        content: &str,
+        format: &str,
+        compression: Option<&str>,
+    ) -> Result<Vec<u8>, AnyError> {
+        let bytes = self.find_formatter(format)?.encode(content)?;
+
+        match self.find_compressor(compression.unwrap_or(NO_COMPRESSION))? {
+            Some(compressor) => compressor.compress(&bytes),
+            None => Ok(bytes),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FormatterRegistry, NO_COMPRESSION};
+
+    #[test]
+    fn it_detects_the_compressed_formats() {
+        let registry = FormatterRegistry::with_builtins();
+        let content = "{\n  \"name\": \"redis\",\n  \"tags\": [\n    1,\n    2\n  ]\n}";
+
+        for format in ["json", "msgpack", "cbor"] {
+            for compression in ["gzip", "zlib", "zstd", "lz4", "snappy", NO_COMPRESSION] {
+                let bytes = registry.encode(content, format, Some(compression)).unwrap();
+                let value = registry.decode(&bytes, None, None).unwrap();
+
+                assert_eq!(value.compression, compression);
+                assert_eq!(value.format, format);
+                assert_eq!(value.content, content);
+            }
+        }
+    }
+
+    #[test]
+    fn it_falls_back_to_raw_and_hex() {
+        let registry = FormatterRegistry::with_builtins();
+
+        let value = registry.decode(b"hello", None, None).unwrap();
+        assert_eq!(
+            (value.format.as_str(), value.content.as_str()),
+            ("raw", "hello")
+        );
+
+        let value = registry.decode(&[0xff, 0x00, 0x10], None, None).unwrap();
+        assert_eq!(
+            (value.format.as_str(), value.content.as_str()),
+            ("hex", "ff0010")
+        );
+        assert_eq!(
+            registry.encode("ff 00 10", "hex", None).unwrap(),
+            vec![0xff, 0x00, 0x10]
+        );
+
+        assert!(registry.decode(b"hello", Some("unknown"), None).is_err());
+    }
+}
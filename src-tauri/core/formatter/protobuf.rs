@@ -0,0 +1,183 @@
+use crate::features::error::AnyError;
+use crate::formatter::ValueFormatter;
+use prost_reflect::prost::Message;
+use prost_reflect::prost_types::FileDescriptorSet;
+use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor};
+use protox::file::{
+    ChainFileResolver, DescriptorSetFileResolver, File, FileResolver, GoogleFileResolver,
+};
+use std::collections::HashMap;
+
+pub const PROTOBUF_FORMAT: &str = "protobuf";
+
+/// The `.proto` sources, keyed by the names used in the `import` statements.
+struct SourceFileResolver(HashMap<String, String>);
+
+impl FileResolver for SourceFileResolver {
+    fn open_file(&self, name: &str) -> Result<File, protox::Error> {
+        match self.0.get(name) {
+            Some(source) => File::from_source(name, source),
+            None => Err(protox::Error::file_not_found(name)),
+        }
+    }
+}
+
+/// The message types compiled from the `.proto` sources and the descriptor sets.
+#[derive(Debug, Clone, Default)]
+pub struct ProtobufSchema {
+    pool: DescriptorPool,
+}
+
+impl ProtobufSchema {
+    /// The sources may import each other, the types in the descriptor sets and the well-known types.
+    pub fn compile(
+        sources: Vec<(String, String)>,
+        descriptor_sets: Vec<Vec<u8>>,
+    ) -> Result<Self, AnyError> {
+        let mut pool = DescriptorPool::new();
+        for descriptor_set in &descriptor_sets {
+            pool.decode_file_descriptor_set(descriptor_set.as_slice())?;
+        }
+
+        if sources.is_empty() {
+            return Ok(Self { pool });
+        }
+
+        let names: Vec<String> = sources.iter().map(|(name, _)| name.clone()).collect();
+
+        let mut resolver = ChainFileResolver::new();
+        resolver.add(SourceFileResolver(sources.into_iter().collect()));
+        resolver.add(DescriptorSetFileResolver::new(FileDescriptorSet {
+            file: pool.file_descriptor_protos().cloned().collect(),
+        }));
+        resolver.add(GoogleFileResolver::new());
+
+        let mut compiler = protox::Compiler::with_file_resolver(resolver);
+        compiler.include_imports(true);
+        compiler.open_files(names)?;
+
+        let mut compiled = compiler.descriptor_pool();
+        for descriptor_set in &descriptor_sets {
+            compiled.decode_file_descriptor_set(descriptor_set.as_slice())?;
+        }
+
+        Ok(Self { pool: compiled })
+    }
+
+    /// The full names of the messages, e.g. `package.Message`.
+    pub fn message_types(&self) -> Vec<String> {
+        self.pool
+            .all_messages()
+            .filter(|message| !message.is_map_entry())
+            .map(|message| message.full_name().to_string())
+            .collect()
+    }
+
+    pub fn formatter(&self, message_type: &str) -> Result<ProtobufFormatter, AnyError> {
+        self.pool
+            .get_message_by_name(message_type)
+            .map(|message| ProtobufFormatter { message })
+            .ok_or_else(|| {
+                AnyError::msg(format!("Unknown protobuf message type: {}.", message_type))
+            })
+    }
+}
+
+/// Presented as JSON with the protobuf JSON mapping.
+///
+/// The bytes hardly tell the message type, so it is never detected and only used when specified.
+pub struct ProtobufFormatter {
+    message: MessageDescriptor,
+}
+
+impl ValueFormatter for ProtobufFormatter {
+    fn name(&self) -> &str {
+        PROTOBUF_FORMAT
+    }
+
+    fn detect(&self, _bytes: &[u8]) -> bool {
+        false
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<String, AnyError> {
+        let message = DynamicMessage::decode(self.message.clone(), bytes)?;
+
+        Ok(serde_json::to_string_pretty(&message)?)
+    }
+
+    fn encode(&self, content: &str) -> Result<Vec<u8>, AnyError> {
+        let mut deserializer = serde_json::Deserializer::from_str(content);
+        let message = DynamicMessage::deserialize(self.message.clone(), &mut deserializer)?;
+        deserializer.end()?;
+
+        Ok(message.encode_to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ProtobufSchema, PROTOBUF_FORMAT};
+    use crate::formatter::FormatterRegistry;
+
+    const USER_PROTO: &str = r#"
+        syntax = "proto3";
+        package demo;
+
+        import "google/protobuf/timestamp.proto";
+        import "role.proto";
+
+        message User {
+            string name = 1;
+            repeated Role roles = 2;
+            google.protobuf.Timestamp created_at = 3;
+        }
+    "#;
+
+    const ROLE_PROTO: &str = r#"
+        syntax = "proto3";
+        package demo;
+
+        enum Role {
+            ROLE_UNSPECIFIED = 0;
+            ROLE_ADMIN = 1;
+        }
+    "#;
+
+    #[test]
+    fn it_decodes_and_encodes_the_mapped_messages() {
+        let schema = ProtobufSchema::compile(
+            vec![
+                ("user.proto".to_string(), USER_PROTO.to_string()),
+                ("role.proto".to_string(), ROLE_PROTO.to_string()),
+            ],
+            vec![],
+        )
+        .unwrap();
+        assert!(schema.message_types().contains(&"demo.User".to_string()));
+
+        let mut registry = FormatterRegistry::with_builtins();
+        registry.register_formatter(schema.formatter("demo.User").unwrap());
+
+        let content =
+            r#"{"name":"redis","roles":["ROLE_ADMIN"],"createdAt":"2024-01-01T00:00:00Z"}"#;
+        let bytes = registry
+            .encode(content, PROTOBUF_FORMAT, Some("gzip"))
+            .unwrap();
+
+        // Only the compression is detected.
+        let value = registry
+            .decode(&bytes, Some(PROTOBUF_FORMAT), None)
+            .unwrap();
+        assert_eq!(value.compression, "gzip");
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&value.content).unwrap(),
+            serde_json::from_str::<serde_json::Value>(content).unwrap()
+        );
+
+        assert_ne!(
+            registry.decode(&bytes, None, None).unwrap().format,
+            PROTOBUF_FORMAT
+        );
+        assert!(schema.formatter("demo.Unknown").is_err());
+    }
+}
@@ -1,3 +1,9 @@
 pub mod cache;
 
 pub mod features;
+
+pub mod tunnel;
+
+pub mod vault;
+
+pub mod formatter;
@@ -0,0 +1,378 @@
+use crate::features::error::AnyError;
+use async_trait::async_trait;
+use russh::client::{self, Handle};
+use russh::keys::key::PublicKey;
+use russh::keys::{check_known_hosts, load_secret_key};
+use russh::Disconnect;
+use std::fmt;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+#[derive(Debug, Clone)]
+pub enum SshTunnelAuth {
+    Password(String),
+    PrivateKey {
+        path: String,
+        passphrase: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct SshTunnelConfig {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub auth: SshTunnelAuth,
+
+    // The target which the ssh server forwards to.
+    pub remote_host: String,
+    pub remote_port: u16,
+
+    // The SHA-256 fingerprint of the server key trusted before.
+    // Only checked when the host is not in `known_hosts`, and any key is trusted if absent.
+    pub host_key_fingerprint: Option<String>,
+}
+
+/// The server key differs from the known one, someone may be in the middle.
+#[derive(Debug)]
+pub struct SshHostKeyMismatch {
+    pub fingerprint: String,
+}
+
+impl fmt::Display for SshHostKeyMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SSH host key mismatch: SHA256:{}.", self.fingerprint)
+    }
+}
+
+impl std::error::Error for SshHostKeyMismatch {}
+
+#[derive(Debug, Default)]
+struct SshHostKeyCheck {
+    fingerprint: Option<String>,
+    mismatched: bool,
+}
+
+struct SshTunnelHandler {
+    host: String,
+    port: u16,
+    trusted_fingerprint: Option<String>,
+    // Read back after the handshake, the handler itself is moved into the session.
+    check: Arc<Mutex<SshHostKeyCheck>>,
+}
+
+#[async_trait]
+impl client::Handler for SshTunnelHandler {
+    type Error = russh::Error;
+
+    // The `known_hosts` of the user wins, then the trusted fingerprint.
+    async fn check_server_key(
+        &mut self,
+        server_public_key: &PublicKey,
+    ) -> Result<bool, Self::Error> {
+        let fingerprint = server_public_key.fingerprint();
+
+        let accepted = match check_known_hosts(&self.host, self.port, server_public_key) {
+            Ok(true) => true,
+            Err(russh::keys::Error::KeyChanged { .. }) => false,
+            // Unknown to `known_hosts`, or there is no such file.
+            _ => self
+                .trusted_fingerprint
+                .as_ref()
+                .map_or(true, |trusted| *trusted == fingerprint),
+        };
+
+        let mut check = self.check.lock().unwrap();
+        check.mismatched = !accepted;
+        check.fingerprint = Some(fingerprint);
+
+        Ok(accepted)
+    }
+}
+
+/// A local port forward through a ssh server.
+///
+/// Every connection accepted on `local_addr` is forwarded to `remote_host:remote_port`
+/// by a `direct-tcpip` channel of the same ssh session.
+pub struct SshTunnel {
+    local_addr: SocketAddr,
+    host_key_fingerprint: String,
+    session: Arc<Handle<SshTunnelHandler>>,
+    handler: Option<JoinHandle<()>>,
+}
+
+impl SshTunnel {
+    pub async fn open(config: SshTunnelConfig) -> Result<Self, AnyError> {
+        let check = Arc::new(Mutex::new(SshHostKeyCheck::default()));
+        let handler = SshTunnelHandler {
+            host: config.host.clone(),
+            port: config.port,
+            trusted_fingerprint: config.host_key_fingerprint.clone(),
+            check: check.clone(),
+        };
+
+        let connected = client::connect(
+            Arc::new(client::Config::default()),
+            (config.host.as_str(), config.port),
+            handler,
+        )
+        .await;
+
+        let SshHostKeyCheck {
+            fingerprint,
+            mismatched,
+        } = std::mem::take(&mut *check.lock().unwrap());
+        let (mut session, host_key_fingerprint) = match (connected, fingerprint) {
+            (_, Some(fingerprint)) if mismatched => {
+                return Err(SshHostKeyMismatch { fingerprint }.into());
+            }
+            (Ok(session), Some(fingerprint)) => (session, fingerprint),
+            (Ok(_), None) => return Err(AnyError::msg("Missing the SSH host key.")),
+            (Err(err), _) => return Err(err.into()),
+        };
+
+        let authenticated = match config.auth {
+            SshTunnelAuth::Password(password) => {
+                session
+                    .authenticate_password(config.username, password)
+                    .await?
+            }
+            SshTunnelAuth::PrivateKey { path, passphrase } => {
+                let key_pair = load_secret_key(path, passphrase.as_deref())?;
+
+                session
+                    .authenticate_publickey(config.username, Arc::new(key_pair))
+                    .await?
+            }
+        };
+
+        if !authenticated {
+            return Err(AnyError::msg("SSH authentication failed."));
+        }
+
+        // Let the system pick a free port.
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let local_addr = listener.local_addr()?;
+        let session = Arc::new(session);
+
+        let forward_session = session.clone();
+        let handler = tokio::spawn(async move {
+            while let Ok((mut socket, originator)) = listener.accept().await {
+                let session = forward_session.clone();
+                let remote_host = config.remote_host.clone();
+                let remote_port = config.remote_port;
+
+                tokio::spawn(async move {
+                    let channel = session
+                        .channel_open_direct_tcpip(
+                            remote_host,
+                            remote_port.into(),
+                            originator.ip().to_string(),
+                            originator.port().into(),
+                        )
+                        .await;
+
+                    // The accepted socket is closed when dropped.
+                    if let Ok(channel) = channel {
+                        let mut stream = channel.into_stream();
+                        let _ = tokio::io::copy_bidirectional(&mut socket, &mut stream).await;
+                    }
+                });
+            }
+        });
+
+        Ok(Self {
+            local_addr,
+            host_key_fingerprint,
+            session,
+            handler: Some(handler),
+        })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// The accepted server key, to be trusted next time.
+    pub fn host_key_fingerprint(&self) -> &str {
+        &self.host_key_fingerprint
+    }
+
+    pub async fn close(mut self) -> Result<(), AnyError> {
+        if let Some(handler) = self.handler.take() {
+            handler.abort();
+        }
+
+        self.session
+            .disconnect(Disconnect::ByApplication, "", "en")
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Drop for SshTunnel {
+    fn drop(&mut self) {
+        // Stop accepting new connections, the session ends with its last handle.
+        if let Some(handler) = self.handler.take() {
+            handler.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SshHostKeyMismatch, SshTunnel, SshTunnelAuth, SshTunnelConfig};
+    use async_trait::async_trait;
+    use russh::keys::key::KeyPair;
+    use russh::server::{self, Auth, Msg, Session};
+    use russh::Channel;
+    use std::sync::Arc;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    // A sshd stand-in which only supports password auth and `direct-tcpip` channels.
+    #[derive(Clone)]
+    struct StandInServer;
+
+    #[async_trait]
+    impl server::Handler for StandInServer {
+        type Error = russh::Error;
+
+        async fn auth_password(&mut self, user: &str, password: &str) -> Result<Auth, Self::Error> {
+            if user == "tester" && password == "secret" {
+                return Ok(Auth::Accept);
+            }
+
+            Ok(Auth::Reject {
+                proceed_with_methods: None,
+            })
+        }
+
+        async fn channel_open_direct_tcpip(
+            &mut self,
+            channel: Channel<Msg>,
+            host_to_connect: &str,
+            port_to_connect: u32,
+            _originator_address: &str,
+            _originator_port: u32,
+            _session: &mut Session,
+        ) -> Result<bool, Self::Error> {
+            let mut target = TcpStream::connect((host_to_connect, port_to_connect as u16)).await?;
+
+            tokio::spawn(async move {
+                let mut stream = channel.into_stream();
+                let _ = tokio::io::copy_bidirectional(&mut target, &mut stream).await;
+            });
+
+            Ok(true)
+        }
+    }
+
+    async fn spawn_stand_in_sshd() -> u16 {
+        let config = Arc::new(server::Config {
+            keys: vec![KeyPair::generate_ed25519().unwrap()],
+            ..Default::default()
+        });
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                let config = config.clone();
+                tokio::spawn(async move {
+                    if let Ok(session) = server::run_stream(config, socket, StandInServer).await {
+                        let _ = session.await;
+                    }
+                });
+            }
+        });
+
+        port
+    }
+
+    async fn spawn_echo_server() -> u16 {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let (mut reader, mut writer) = socket.split();
+                    let _ = tokio::io::copy(&mut reader, &mut writer).await;
+                });
+            }
+        });
+
+        port
+    }
+
+    fn tunnel_config(ssh_port: u16, remote_port: u16, password: &str) -> SshTunnelConfig {
+        SshTunnelConfig {
+            host: "127.0.0.1".to_string(),
+            port: ssh_port,
+            username: "tester".to_string(),
+            auth: SshTunnelAuth::Password(password.to_string()),
+            remote_host: "127.0.0.1".to_string(),
+            remote_port,
+            host_key_fingerprint: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_forwards_through_the_tunnel() {
+        let ssh_port = spawn_stand_in_sshd().await;
+        let echo_port = spawn_echo_server().await;
+
+        let tunnel = SshTunnel::open(tunnel_config(ssh_port, echo_port, "secret"))
+            .await
+            .unwrap();
+
+        let mut socket = TcpStream::connect(tunnel.local_addr()).await.unwrap();
+        socket.write_all(b"PING").await.unwrap();
+
+        let mut buf = [0_u8; 4];
+        socket.read_exact(&mut buf).await.unwrap();
+        assert_eq!(&buf, b"PING");
+
+        tunnel.close().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn it_rejects_wrong_credentials() {
+        let ssh_port = spawn_stand_in_sshd().await;
+
+        let result = SshTunnel::open(tunnel_config(ssh_port, 6379, "wrong")).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_changed_host_key() {
+        let ssh_port = spawn_stand_in_sshd().await;
+        let echo_port = spawn_echo_server().await;
+
+        // Trusted on first use.
+        let tunnel = SshTunnel::open(tunnel_config(ssh_port, echo_port, "secret"))
+            .await
+            .unwrap();
+        let fingerprint = tunnel.host_key_fingerprint().to_string();
+        tunnel.close().await.unwrap();
+
+        let mut config = tunnel_config(ssh_port, echo_port, "secret");
+        config.host_key_fingerprint = Some(fingerprint);
+        SshTunnel::open(config)
+            .await
+            .unwrap()
+            .close()
+            .await
+            .unwrap();
+
+        let mut config = tunnel_config(ssh_port, echo_port, "secret");
+        config.host_key_fingerprint = Some("not-the-key".to_string());
+        let err = SshTunnel::open(config).await.err().unwrap();
+        assert!(err.downcast_ref::<SshHostKeyMismatch>().is_some());
+    }
+}
@@ -0,0 +1,189 @@
+use crate::features::error::AnyError;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+const NONCE_LEN: usize = 12;
+
+/// A secret saved along with the connections.
+///
+/// Told apart by the shape instead of the content, a string is always plaintext,
+/// either typed by the user or saved before sealing, and only the vault makes the sealed ones.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Secret {
+    Sealed { sealed: String },
+    Plain(String),
+}
+
+impl Secret {
+    /// The sealed ones have to be opened by the vault first.
+    pub fn plain(&self) -> Option<&str> {
+        match self {
+            Self::Plain(value) => Some(value),
+            Self::Sealed { .. } => None,
+        }
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Self::Plain(value)
+    }
+}
+
+/// Encrypts the secrets at rest with a locally generated key.
+pub struct SecretsVault {
+    cipher: Aes256Gcm,
+}
+
+impl SecretsVault {
+    pub fn new(key: &[u8; 32]) -> Self {
+        Self {
+            cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key)),
+        }
+    }
+
+    /// Loads the key file, generates a new one if not existed.
+    pub fn load_or_generate(key_file: PathBuf) -> Result<Self, AnyError> {
+        if key_file.exists() {
+            let content = fs::read_to_string(&key_file)?;
+            let key: [u8; 32] = BASE64
+                .decode(content.trim())?
+                .try_into()
+                .map_err(|_| AnyError::msg("Invalid secrets key file."))?;
+
+            return Ok(Self::new(&key));
+        }
+
+        if let Some(directory) = key_file.parent() {
+            // Create the folders, if existed, bypass.
+            fs_extra::dir::create_all(directory, false)?;
+        }
+
+        let key = Aes256Gcm::generate_key(OsRng);
+
+        let mut options = fs::File::options();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+
+            // Only readable by the current user.
+            options.mode(0o600);
+        }
+
+        let mut handle = options.open(&key_file)?;
+        handle.write_all(BASE64.encode(key).as_bytes())?;
+
+        Ok(Self::new(&key.into()))
+    }
+
+    pub fn seal(&self, secret: &Secret) -> Result<Secret, AnyError> {
+        let value = match secret {
+            Secret::Plain(value) if !value.is_empty() => value,
+            // Nothing to hide, or sealed already.
+            _ => return Ok(secret.clone()),
+        };
+
+        let nonce = Aes256Gcm::generate_nonce(OsRng);
+        let mut sealed = nonce.to_vec();
+        sealed.append(
+            &mut self
+                .cipher
+                .encrypt(&nonce, value.as_bytes())
+                .map_err(|_| AnyError::msg("Failed to seal the secret."))?,
+        );
+
+        Ok(Secret::Sealed {
+            sealed: BASE64.encode(sealed),
+        })
+    }
+
+    pub fn open(&self, secret: &Secret) -> Result<Secret, AnyError> {
+        let sealed = match secret {
+            Secret::Sealed { sealed } => BASE64.decode(sealed)?,
+            Secret::Plain(_) => return Ok(secret.clone()),
+        };
+
+        if sealed.len() < NONCE_LEN {
+            return Err(AnyError::msg("Invalid sealed secret."));
+        }
+
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let opened = self
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| AnyError::msg("Failed to open the secret."))?;
+
+        Ok(Secret::Plain(String::from_utf8(opened)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Secret, SecretsVault};
+
+    fn plain(value: &str) -> Secret {
+        Secret::Plain(value.to_string())
+    }
+
+    #[test]
+    fn it_seals_and_opens() {
+        let vault = SecretsVault::new(&[7_u8; 32]);
+
+        let sealed = vault.seal(&plain("secret")).unwrap();
+        assert!(sealed.plain().is_none());
+        assert!(!serde_json::to_string(&sealed).unwrap().contains("secret"));
+        assert_eq!(vault.seal(&sealed).unwrap(), sealed);
+        assert_eq!(vault.open(&sealed).unwrap(), plain("secret"));
+
+        // Legacy plaintext and empty values.
+        assert_eq!(vault.open(&plain("plain")).unwrap(), plain("plain"));
+        assert_eq!(vault.seal(&plain("")).unwrap(), plain(""));
+
+        // Sealed by another key.
+        assert!(SecretsVault::new(&[8_u8; 32]).open(&sealed).is_err());
+    }
+
+    #[test]
+    fn it_tells_the_sealed_ones_by_the_shape() {
+        let vault = SecretsVault::new(&[7_u8; 32]);
+
+        // Looks like a sealed one, but typed by the user.
+        let typed: Secret = serde_json::from_str(r#""sealed:v1:AAAA""#).unwrap();
+        assert_eq!(typed, plain("sealed:v1:AAAA"));
+
+        let sealed = vault.seal(&typed).unwrap();
+        let saved = serde_json::to_string(&sealed).unwrap();
+        assert!(saved.starts_with(r#"{"sealed":"#));
+
+        let loaded: Secret = serde_json::from_str(&saved).unwrap();
+        assert_eq!(vault.open(&loaded).unwrap(), typed);
+    }
+
+    #[test]
+    fn it_reuses_the_generated_key_file() {
+        let key_file = std::env::temp_dir()
+            .join(format!("tauri-redis-vault-{}", std::process::id()))
+            .join("secrets.key");
+
+        let sealed = SecretsVault::load_or_generate(key_file.clone())
+            .unwrap()
+            .seal(&plain("secret"))
+            .unwrap();
+        let opened = SecretsVault::load_or_generate(key_file.clone())
+            .unwrap()
+            .open(&sealed)
+            .unwrap();
+
+        let _ = std::fs::remove_dir_all(key_file.parent().unwrap());
+
+        assert_eq!(opened, plain("secret"));
+    }
+}
@@ -1,14 +1,36 @@
-import type { IpcConnection, IpcConnections, SaveIpcConnectionPayload } from '$lib/types';
+import type {
+	IpcConnection,
+	IpcConnectionGroup,
+	IpcConnections,
+	IpcConnectionSecrets,
+	IpcConnectionStatePayload,
+	IpcConnectionTestResult,
+	IpcImportConnectionsResult,
+	IpcProtobufMessageMapping,
+	IpcProtobufSchemaFile,
+	IpcProtobufSchemas,
+	SaveIpcConnectionGroupPayload,
+	SaveIpcConnectionPayload
+} from '$lib/types';
 import { fetchIpc } from '$lib/utils/async';
+import { listen } from '@tauri-apps/api/event';
 
 export function fetchSaveConnection(payload: SaveIpcConnectionPayload) {
 	return fetchIpc('save_connection', { connectionInfo: JSON.stringify(payload) });
 }
 
+export function fetchTestConnection(payload: SaveIpcConnectionPayload) {
+	return fetchIpc<IpcConnectionTestResult>('test_connection', { connectionInfo: JSON.stringify(payload) });
+}
+
 export function fetchGetConnections() {
 	return fetchIpc<IpcConnections>('get_connections');
 }
 
+export function fetchGetConnectionSecrets(guid: IpcConnection['guid']) {
+	return fetchIpc<IpcConnectionSecrets>('get_connection_secrets', { guid });
+}
+
 export function fetchReleaseConnection(guid: IpcConnection['guid']) {
 	return fetchIpc<IpcConnections>('release_connection', { guid });
 }
@@ -20,3 +42,66 @@ export function fetchEstablishConnection(guid: IpcConnection['guid']) {
 export function fetchRemoveConnection(guid: IpcConnection['guid']) {
 	return fetchIpc<IpcConnections>('remove_connection', { guid });
 }
+
+export function fetchExportConnections(guids: Array<IpcConnection['guid']>, withSecrets: boolean) {
+	return fetchIpc<string>('export_connections', { guids, withSecrets });
+}
+
+export function fetchImportConnections(bundle: string) {
+	return fetchIpc<IpcImportConnectionsResult>('import_connections', { bundle });
+}
+
+export function fetchParseConnectionUri(uri: string) {
+	return fetchIpc<SaveIpcConnectionPayload>('parse_connection_uri', { uri });
+}
+
+export function fetchGetConnectionUri(guid: IpcConnection['guid'], withSecrets: boolean) {
+	return fetchIpc<string>('get_connection_uri', { guid, withSecrets });
+}
+
+export function fetchGetConnectionGroups() {
+	return fetchIpc<Array<IpcConnectionGroup>>('get_connection_groups');
+}
+
+export function fetchSaveConnectionGroup(payload: SaveIpcConnectionGroupPayload) {
+	return fetchIpc<IpcConnectionGroup['guid']>('save_connection_group', { groupInfo: JSON.stringify(payload) });
+}
+
+export function fetchRemoveConnectionGroup(guid: IpcConnectionGroup['guid']) {
+	return fetchIpc('remove_connection_group', { guid });
+}
+
+export function fetchReorderConnectionGroups(guids: Array<IpcConnectionGroup['guid']>) {
+	return fetchIpc('reorder_connection_groups', { guids });
+}
+
+export function fetchMoveConnection(
+	guid: IpcConnection['guid'],
+	group: IpcConnectionGroup['guid'] | null,
+	index: number | null
+) {
+	return fetchIpc('move_connection', { guid, group, index });
+}
+
+export function fetchGetProtobufSchemas(guid: IpcConnection['guid']) {
+	return fetchIpc<IpcProtobufSchemas>('get_protobuf_schemas', { guid });
+}
+
+// Replies the compiled message types.
+export function fetchSaveProtobufSchemas(
+	guid: IpcConnection['guid'],
+	files: Array<IpcProtobufSchemaFile>,
+	mappings: Array<IpcProtobufMessageMapping>
+) {
+	return fetchIpc<Array<string>>('save_protobuf_schemas', { guid, files, mappings });
+}
+
+export function fetchListProtobufMessageTypes(guid: IpcConnection['guid']) {
+	return fetchIpc<Array<string>>('list_protobuf_message_types', { guid });
+}
+
+export function listenConnectionStateChanged(handler: (payload: IpcConnectionStatePayload) => void) {
+	return listen<IpcConnectionStatePayload>('redis:connection:state-changed', function (event) {
+		handler(event.payload);
+	});
+}
@@ -1,18 +1,55 @@
 import type {
+	IpcClientKeys,
 	IpcClientMetrics,
 	IpcConnection,
+	IpcConnectionAddress,
+	IpcFormattedValue,
+	IpcHashKeyFormattedField,
 	IpcHashKeyValues,
+	IpcKeyName,
+	IpcKeyNamespacePage,
+	IpcKeyspaceInfo,
+	IpcListKeyPage,
+	IpcMemberName,
+	IpcNewStreamKeyField,
+	IpcNewZSetKeyMember,
+	IpcRedisBytes,
 	IpcRenameKeyPayload,
+	IpcScanFilter,
+	IpcScanId,
+	IpcScanProgressPayload,
+	IpcScannerOptions,
+	IpcScanPage,
+	IpcSetKeyMembers,
+	IpcStreamKeyAutoClaimQuery,
+	IpcStreamKeyAutoClaimResult,
+	IpcStreamKeyConsumer,
+	IpcStreamKeyEntry,
+	IpcStreamKeyGroup,
+	IpcStreamKeyPage,
+	IpcStreamKeyPendingEntry,
+	IpcStreamKeyPendingQuery,
+	IpcStreamKeyPendingSummary,
+	IpcValueFormatOptions,
+	IpcValueFormatters,
+	IpcZSetKeyMember,
+	IpcZSetKeyPage,
+	IpcZSetKeyRange,
 	SaveIpcNewKeyPayload,
 	SetIpcKeyContentTypeStringPayload,
 	SetIpcKeyTTLPayload
 } from '$lib/types';
 import { fetchIpc } from '$lib/utils/async';
+import { listen } from '@tauri-apps/api/event';
 
 export function fetchDbNums(guid: IpcConnection['guid']) {
 	return fetchIpc<number>('db_nums', { guid });
 }
 
+export function fetchKeyspaceSummary(guid: IpcConnection['guid']) {
+	return fetchIpc<Array<IpcKeyspaceInfo>>('keyspace_summary', { guid });
+}
+
 export function fetchFlushAll(guid: IpcConnection['guid']) {
 	return fetchIpc<void>('flush_all', { guid });
 }
@@ -21,40 +58,66 @@ export function fetchSwitchDb(guid: IpcConnection['guid'], db: number) {
 	return fetchIpc<void>('switch_db', { guid, db });
 }
 
+export function fetchCurrentDb(guid: IpcConnection['guid']) {
+	return fetchIpc<number>('current_db', { guid });
+}
+
 export function fetchListRedisClientMetrics(guid: IpcConnection['guid']) {
 	return fetchIpc<IpcClientMetrics>('list_client_metrics', { guid });
 }
 
+export function fetchGetMasterAddress(guid: IpcConnection['guid']) {
+	return fetchIpc<IpcConnectionAddress>('get_master_address', { guid });
+}
+
 export function fetchListRedisAllKeys(guid: IpcConnection['guid'], conditionPart?: string) {
-	return fetchIpc<string[]>('list_all_keys', { guid, conditionPart });
+	return fetchIpc<IpcClientKeys>('list_all_keys', { guid, conditionPart });
 }
 
-export function fetchScanRedisAllKeys(guid: IpcConnection['guid'], conditionPart?: string, forceNew?: boolean) {
-	return fetchIpc<string[]>('scan_all_keys', { guid, conditionPart, forceNew });
+// The default scanner of each kind is shared when `scanId` is omitted.
+export function fetchScanRedisAllKeys(
+	guid: IpcConnection['guid'],
+	conditionPart?: string,
+	forceNew?: boolean,
+	scanId?: IpcScanId,
+	filter?: IpcScanFilter
+) {
+	return fetchIpc<IpcClientKeys>('scan_all_keys', { guid, conditionPart, forceNew, scanId, filter });
 }
 
-export function fetchRefreshScanRedisAllKeys(guid: IpcConnection['guid'], conditionPart?: string, offset?: number) {
-	return fetchIpc<string[]>('refresh_scanned_all_keys', { guid, conditionPart, offset });
+export function fetchRefreshScanRedisAllKeys(
+	guid: IpcConnection['guid'],
+	conditionPart?: string,
+	offset?: number,
+	scanId?: IpcScanId,
+	filter?: IpcScanFilter
+) {
+	return fetchIpc<IpcClientKeys>('refresh_scanned_all_keys', { guid, conditionPart, offset, scanId, filter });
+}
+
+// The root level is scanned without the prefix.
+export function fetchScanKeyNamespaceTree(guid: IpcConnection['guid'], prefix?: IpcKeyName, forceNew?: boolean) {
+	return fetchIpc<IpcKeyNamespacePage>('scan_key_namespace_tree', { guid, prefix, forceNew });
 }
 
 export function fetchCreateNewKey(guid: IpcConnection['guid'], params: SaveIpcNewKeyPayload) {
 	return fetchIpc<void>('create_new_key', { guid, keyName: params.name, keyType: params.type });
 }
 
-export function fetchRemoveKey(guid: IpcConnection['guid'], keyName: string) {
+export function fetchRemoveKey(guid: IpcConnection['guid'], keyName: IpcKeyName) {
 	return fetchIpc<void>('remove_key', { guid, keyName });
 }
 
-export function fetchGetKeyType(guid: IpcConnection['guid'], keyName: string) {
+export function fetchGetKeyType(guid: IpcConnection['guid'], keyName: IpcKeyName) {
 	return fetchIpc<string>('get_key_type', { guid, keyName });
 }
 
-export function fetchGetKeyTTL(guid: IpcConnection['guid'], keyName: string) {
+export function fetchGetKeyTTL(guid: IpcConnection['guid'], keyName: IpcKeyName) {
 	return fetchIpc<number>('get_key_ttl', { guid, keyName });
 }
 
-export function fetchGetKeyContentTypeString(guid: IpcConnection['guid'], keyName: string) {
-	return fetchIpc<string>('get_key_content_type_string', { guid, keyName });
+export function fetchGetKeyContentTypeString(guid: IpcConnection['guid'], keyName: IpcKeyName) {
+	return fetchIpc<IpcRedisBytes>('get_key_content_type_string', { guid, keyName });
 }
 
 export function fetchSetKeyTTL(guid: IpcConnection['guid'], params: SetIpcKeyTTLPayload) {
@@ -69,28 +132,340 @@ export function fetchRenameKey(guid: IpcConnection['guid'], params: IpcRenameKey
 	return fetchIpc<void>('rename_key', { guid, keyName: params.name, newKeyName: params.newName });
 }
 
-export function fetchGetKeyContentTypeHash(guid: IpcConnection['guid'], keyName: string) {
+export function fetchGetKeyContentTypeHash(guid: IpcConnection['guid'], keyName: IpcKeyName) {
 	return fetchIpc<IpcHashKeyValues>('get_key_content_type_hash', { guid, keyName });
 }
 
-export function fetchRemoveHashKeyField(guid: IpcConnection['guid'], keyName: string, fieldName: string) {
+export function fetchRemoveHashKeyField(guid: IpcConnection['guid'], keyName: IpcKeyName, fieldName: IpcMemberName) {
 	return fetchIpc<void>('remove_hash_key_field', { guid, keyName, fieldName });
 }
 
 export function fetchHScanKeyAllValues(
 	guid: IpcConnection['guid'],
-	keyName: string,
+	keyName: IpcKeyName,
 	conditionPart?: string,
-	forceNew?: boolean
+	forceNew?: boolean,
+	scanId?: IpcScanId
 ) {
-	return fetchIpc<IpcHashKeyValues>('hscan_key_all_values', { guid, keyName, conditionPart, forceNew });
+	return fetchIpc<IpcHashKeyValues>('hscan_key_all_values', { guid, keyName, conditionPart, forceNew, scanId });
 }
 
 export function fetchRefreshHScanKeyAllValues(
 	guid: IpcConnection['guid'],
-	keyName: string,
+	keyName: IpcKeyName,
+	conditionPart?: string,
+	offset?: number,
+	scanId?: IpcScanId
+) {
+	return fetchIpc<IpcHashKeyValues>('refresh_hscaned_key_all_values', { guid, keyName, conditionPart, offset, scanId });
+}
+
+export function fetchGetKeyContentTypeList(guid: IpcConnection['guid'], keyName: IpcKeyName, start: number, count: number) {
+	return fetchIpc<IpcListKeyPage>('get_key_content_type_list', { guid, keyName, start, count });
+}
+
+export function fetchPushListKeyItems(guid: IpcConnection['guid'], keyName: IpcKeyName, items: Array<IpcMemberName>, head?: boolean) {
+	return fetchIpc<number>('push_list_key_items', { guid, keyName, items, head });
+}
+
+export function fetchSetListKeyItem(guid: IpcConnection['guid'], keyName: IpcKeyName, index: number, value: IpcMemberName) {
+	return fetchIpc<void>('set_list_key_item', { guid, keyName, index, value });
+}
+
+export function fetchRemoveListKeyItem(guid: IpcConnection['guid'], keyName: IpcKeyName, value: IpcMemberName, count?: number) {
+	return fetchIpc<number>('remove_list_key_item', { guid, keyName, value, count });
+}
+
+export function fetchInsertListKeyItem(
+	guid: IpcConnection['guid'],
+	keyName: IpcKeyName,
+	pivot: IpcMemberName,
+	value: IpcMemberName,
+	before?: boolean
+) {
+	return fetchIpc<number>('insert_list_key_item', { guid, keyName, pivot, value, before });
+}
+
+export function fetchSScanKeyAllMembers(
+	guid: IpcConnection['guid'],
+	keyName: IpcKeyName,
 	conditionPart?: string,
-	offset?: number
+	forceNew?: boolean,
+	scanId?: IpcScanId
+) {
+	return fetchIpc<IpcSetKeyMembers>('sscan_key_all_members', { guid, keyName, conditionPart, forceNew, scanId });
+}
+
+export function fetchRefreshSScanKeyAllMembers(
+	guid: IpcConnection['guid'],
+	keyName: IpcKeyName,
+	conditionPart?: string,
+	offset?: number,
+	scanId?: IpcScanId
+) {
+	return fetchIpc<IpcSetKeyMembers>('refresh_sscaned_key_all_members', { guid, keyName, conditionPart, offset, scanId });
+}
+
+export function fetchGetSetKeyMembersCount(guid: IpcConnection['guid'], keyName: IpcKeyName) {
+	return fetchIpc<number>('get_set_key_members_count', { guid, keyName });
+}
+
+export function fetchAddSetKeyMembers(guid: IpcConnection['guid'], keyName: IpcKeyName, members: Array<IpcMemberName>) {
+	return fetchIpc<number>('add_set_key_members', { guid, keyName, members });
+}
+
+export function fetchRemoveSetKeyMembers(guid: IpcConnection['guid'], keyName: IpcKeyName, members: Array<IpcMemberName>) {
+	return fetchIpc<number>('remove_set_key_members', { guid, keyName, members });
+}
+
+export function fetchIsSetKeyMember(guid: IpcConnection['guid'], keyName: IpcKeyName, member: IpcMemberName) {
+	return fetchIpc<boolean>('is_set_key_member', { guid, keyName, member });
+}
+
+export function fetchMoveSetKeyMember(guid: IpcConnection['guid'], keyName: IpcKeyName, destination: IpcKeyName, member: IpcMemberName) {
+	return fetchIpc<boolean>('move_set_key_member', { guid, keyName, destination, member });
+}
+
+export function fetchGetKeyContentTypeZSet(
+	guid: IpcConnection['guid'],
+	keyName: IpcKeyName,
+	start: number,
+	count: number,
+	rev?: boolean
+) {
+	return fetchIpc<IpcZSetKeyPage>('get_key_content_type_zset', { guid, keyName, start, count, rev });
+}
+
+export function fetchRangeZSetKeyByScore(guid: IpcConnection['guid'], keyName: IpcKeyName, range: IpcZSetKeyRange) {
+	return fetchIpc<Array<IpcZSetKeyMember>>('range_zset_key_by_score', { guid, keyName, range });
+}
+
+export function fetchRangeZSetKeyByLex(guid: IpcConnection['guid'], keyName: IpcKeyName, range: IpcZSetKeyRange) {
+	return fetchIpc<Array<IpcRedisBytes>>('range_zset_key_by_lex', { guid, keyName, range });
+}
+
+export function fetchZScanKeyAllMembers(
+	guid: IpcConnection['guid'],
+	keyName: IpcKeyName,
+	conditionPart?: string,
+	forceNew?: boolean,
+	scanId?: IpcScanId
+) {
+	return fetchIpc<Array<IpcZSetKeyMember>>('zscan_key_all_members', { guid, keyName, conditionPart, forceNew, scanId });
+}
+
+export function fetchRefreshZScanKeyAllMembers(
+	guid: IpcConnection['guid'],
+	keyName: IpcKeyName,
+	conditionPart?: string,
+	offset?: number,
+	scanId?: IpcScanId
+) {
+	return fetchIpc<Array<IpcZSetKeyMember>>('refresh_zscaned_key_all_members', {
+		guid,
+		keyName,
+		conditionPart,
+		offset,
+		scanId
+	});
+}
+
+export function fetchAddZSetKeyMembers(guid: IpcConnection['guid'], keyName: IpcKeyName, members: Array<IpcNewZSetKeyMember>) {
+	return fetchIpc<number>('add_zset_key_members', { guid, keyName, members });
+}
+
+export function fetchIncrZSetKeyMemberScore(guid: IpcConnection['guid'], keyName: IpcKeyName, member: IpcMemberName, increment: number) {
+	return fetchIpc<number>('incr_zset_key_member_score', { guid, keyName, member, increment });
+}
+
+export function fetchSetZSetKeyMemberScore(guid: IpcConnection['guid'], keyName: IpcKeyName, member: IpcMemberName, score: number) {
+	return fetchIpc<boolean>('set_zset_key_member_score', { guid, keyName, member, score });
+}
+
+export function fetchRemoveZSetKeyMembers(guid: IpcConnection['guid'], keyName: IpcKeyName, members: Array<IpcMemberName>) {
+	return fetchIpc<number>('remove_zset_key_members', { guid, keyName, members });
+}
+
+export function fetchGetKeyContentTypeStream(
+	guid: IpcConnection['guid'],
+	keyName: IpcKeyName,
+	count: number,
+	start?: string,
+	end?: string,
+	rev?: boolean
+) {
+	return fetchIpc<IpcStreamKeyPage>('get_key_content_type_stream', { guid, keyName, start, end, count, rev });
+}
+
+export function fetchAddStreamKeyEntry(
+	guid: IpcConnection['guid'],
+	keyName: IpcKeyName,
+	fields: Array<IpcNewStreamKeyField>,
+	id?: string
+) {
+	return fetchIpc<string>('add_stream_key_entry', { guid, keyName, id, fields });
+}
+
+export function fetchRemoveStreamKeyEntries(guid: IpcConnection['guid'], keyName: IpcKeyName, ids: Array<string>) {
+	return fetchIpc<number>('remove_stream_key_entries', { guid, keyName, ids });
+}
+
+export function fetchGetStreamKeyGroups(guid: IpcConnection['guid'], keyName: IpcKeyName) {
+	return fetchIpc<Array<IpcStreamKeyGroup>>('get_stream_key_groups', { guid, keyName });
+}
+
+export function fetchGetStreamKeyGroupConsumers(guid: IpcConnection['guid'], keyName: IpcKeyName, group: string) {
+	return fetchIpc<Array<IpcStreamKeyConsumer>>('get_stream_key_group_consumers', { guid, keyName, group });
+}
+
+export function fetchGetStreamKeyPendingSummary(guid: IpcConnection['guid'], keyName: IpcKeyName, group: string) {
+	return fetchIpc<IpcStreamKeyPendingSummary>('get_stream_key_pending_summary', { guid, keyName, group });
+}
+
+export function fetchGetStreamKeyPendingEntries(
+	guid: IpcConnection['guid'],
+	keyName: IpcKeyName,
+	group: string,
+	query: IpcStreamKeyPendingQuery
+) {
+	return fetchIpc<Array<IpcStreamKeyPendingEntry>>('get_stream_key_pending_entries', { guid, keyName, group, query });
+}
+
+export function fetchCreateStreamKeyGroup(
+	guid: IpcConnection['guid'],
+	keyName: IpcKeyName,
+	group: string,
+	id?: string,
+	mkstream?: boolean
 ) {
-	return fetchIpc<IpcHashKeyValues>('refresh_hscaned_key_all_values', { guid, keyName, conditionPart, offset });
+	return fetchIpc<void>('create_stream_key_group', { guid, keyName, group, id, mkstream });
+}
+
+export function fetchDestroyStreamKeyGroup(guid: IpcConnection['guid'], keyName: IpcKeyName, group: string) {
+	return fetchIpc<boolean>('destroy_stream_key_group', { guid, keyName, group });
+}
+
+export function fetchSetStreamKeyGroupId(guid: IpcConnection['guid'], keyName: IpcKeyName, group: string, id: string) {
+	return fetchIpc<void>('set_stream_key_group_id', { guid, keyName, group, id });
+}
+
+export function fetchAckStreamKeyEntries(guid: IpcConnection['guid'], keyName: IpcKeyName, group: string, ids: Array<string>) {
+	return fetchIpc<number>('ack_stream_key_entries', { guid, keyName, group, ids });
+}
+
+export function fetchClaimStreamKeyEntries(
+	guid: IpcConnection['guid'],
+	keyName: IpcKeyName,
+	group: string,
+	consumer: string,
+	minIdleTime: number,
+	ids: Array<string>
+) {
+	return fetchIpc<Array<IpcStreamKeyEntry>>('claim_stream_key_entries', {
+		guid,
+		keyName,
+		group,
+		consumer,
+		minIdleTime,
+		ids
+	});
+}
+
+export function fetchAutoClaimStreamKeyEntries(
+	guid: IpcConnection['guid'],
+	keyName: IpcKeyName,
+	group: string,
+	query: IpcStreamKeyAutoClaimQuery
+) {
+	return fetchIpc<IpcStreamKeyAutoClaimResult>('auto_claim_stream_key_entries', { guid, keyName, group, query });
+}
+
+export function fetchIsJsonModuleLoaded(guid: IpcConnection['guid']) {
+	return fetchIpc<boolean>('is_json_module_loaded', { guid });
+}
+
+// The path defaults to `$`, so the reply is an array of the matched values.
+export function fetchGetKeyContentTypeJson(guid: IpcConnection['guid'], keyName: IpcKeyName, path?: string) {
+	return fetchIpc<string>('get_key_content_type_json', { guid, keyName, path });
+}
+
+export function fetchSetJsonKeyValue(guid: IpcConnection['guid'], keyName: IpcKeyName, value: string, path?: string) {
+	return fetchIpc<void>('set_json_key_value', { guid, keyName, path, value });
+}
+
+export function fetchRemoveJsonKeyPath(guid: IpcConnection['guid'], keyName: IpcKeyName, path: string) {
+	return fetchIpc<number>('remove_json_key_path', { guid, keyName, path });
+}
+
+export function fetchAppendJsonKeyArray(guid: IpcConnection['guid'], keyName: IpcKeyName, path: string, values: Array<string>) {
+	return fetchIpc<Array<number | null>>('append_json_key_array', { guid, keyName, path, values });
+}
+
+export function fetchGetJsonKeyType(guid: IpcConnection['guid'], keyName: IpcKeyName, path?: string) {
+	return fetchIpc<Array<string>>('get_json_key_type', { guid, keyName, path });
+}
+
+export function fetchListValueFormatters() {
+	return fetchIpc<IpcValueFormatters>('list_value_formatters');
+}
+
+// The mapped protobuf message type is used when the format is not specified.
+export function fetchGetKeyContentTypeStringFormatted(
+	guid: IpcConnection['guid'],
+	keyName: IpcKeyName,
+	options?: IpcValueFormatOptions
+) {
+	return fetchIpc<IpcFormattedValue>('get_key_content_type_string_formatted', { guid, keyName, options });
+}
+
+// The format is required when writing back, usually the detected one.
+export function fetchSetKeyContentTypeStringFormatted(
+	guid: IpcConnection['guid'],
+	keyName: IpcKeyName,
+	content: string,
+	options: IpcValueFormatOptions
+) {
+	return fetchIpc<void>('set_key_content_type_string_formatted', { guid, keyName, content, options });
+}
+
+export function fetchGetHashKeyFieldFormatted(
+	guid: IpcConnection['guid'],
+	keyName: IpcKeyName,
+	fieldName: IpcKeyName,
+	options?: IpcValueFormatOptions
+) {
+	return fetchIpc<IpcFormattedValue>('get_hash_key_field_formatted', { guid, keyName, fieldName, options });
+}
+
+export function fetchSetHashKeyFieldFormatted(
+	guid: IpcConnection['guid'],
+	keyName: IpcKeyName,
+	field: IpcHashKeyFormattedField,
+	options: IpcValueFormatOptions
+) {
+	return fetchIpc<void>('set_hash_key_field_formatted', { guid, keyName, field, options });
+}
+
+// Replaces the scanner with the same id, and returns its first page.
+export function fetchCreateScanner(guid: IpcConnection['guid'], scanId: IpcScanId, options: IpcScannerOptions) {
+	return fetchIpc<IpcScanPage>('create_scanner', { guid, scanId, options });
+}
+
+export function fetchContinueScanner(guid: IpcConnection['guid'], scanId: IpcScanId) {
+	return fetchIpc<IpcScanPage>('continue_scanner', { guid, scanId });
+}
+
+export function fetchCloseScanner(guid: IpcConnection['guid'], scanId: IpcScanId) {
+	return fetchIpc<boolean>('close_scanner', { guid, scanId });
+}
+
+// The running scan returns with an error, the default scanners are cancelled with their kinds as the scan ids.
+export function fetchCancelScan(guid: IpcConnection['guid'], scanId: IpcScanId) {
+	return fetchIpc<boolean>('cancel_scan', { guid, scanId });
+}
+
+export function listenScanProgress(handler: (payload: IpcScanProgressPayload) => void) {
+	return listen<IpcScanProgressPayload>('redis:scan:progress', function (event) {
+		handler(event.payload);
+	});
 }
@@ -6,17 +6,102 @@ export type SaveIpcConnectionPayload = {
 	connectionName?: IpcConnection['connectionName'];
 	separator?: IpcConnection['separator'];
 	readonly: IpcConnection['readonly'];
+	db?: IpcConnection['db'];
+	kind?: IpcConnection['kind'];
+	tls?: IpcConnection['tls'];
+	ssh?: IpcConnection['ssh'];
+	reconnect?: IpcConnection['reconnect'];
+	env?: IpcConnection['env'];
 	guid?: IpcConnection['guid'];
 };
 
+export type IpcConnectionAddress = {
+	host: string;
+	port: number;
+};
+
+export type IpcConnectionKind =
+	| { type: 'standalone' }
+	| {
+			type: 'sentinel';
+			hosts: Array<IpcConnectionAddress>;
+			masterName: string;
+			username?: string;
+			password?: string;
+	  }
+	| { type: 'cluster'; hosts: Array<IpcConnectionAddress> }
+	| { type: 'unix'; path: string };
+
+export type IpcConnectionTls = {
+	enabled: boolean;
+	caFile?: string;
+	clientCertFile?: string;
+	clientKeyFile?: string;
+	// Only for the encrypted PKCS#8 keys, never returned in the connections list.
+	clientKeyPassphrase?: string;
+	serverName?: string;
+	insecureSkipVerify: boolean;
+};
+
+export type IpcConnectionSsh = {
+	enabled: boolean;
+	host: string;
+	port: number;
+	username: string;
+	password?: string;
+	privateKeyFile?: string;
+	passphrase?: string;
+	// Trusted on first use, send an empty one to trust a replaced server key.
+	hostKeyFingerprint?: string;
+};
+
+export type IpcConnectionReconnect = {
+	enabled: boolean;
+	maxAttempts: number;
+	minDelay: number;
+	maxDelay: number;
+};
+
+export type IpcConnectionState = 'connecting' | 'connected' | 'reconnecting' | 'disconnected' | 'auth-failed';
+
+export type IpcConnectionStatePayload = {
+	guid: IpcConnection['guid'];
+	state: IpcConnectionState;
+};
+
+export type IpcConnectionEnv = 'untagged' | 'dev' | 'staging' | 'prod';
+
+export type IpcConnectionGroup = {
+	name: string;
+	color?: string;
+
+	guid: string;
+	createdAt: string;
+	updatedAt: string;
+};
+
+export type SaveIpcConnectionGroupPayload = {
+	name: IpcConnectionGroup['name'];
+	color?: IpcConnectionGroup['color'];
+	guid?: IpcConnectionGroup['guid'];
+};
+
 export type IpcConnection = {
 	host: string;
 	port: number;
-	password: string;
+	// Never filled in the connections list.
+	password?: string;
 	username: string;
 	connectionName: string;
 	separator: string;
 	readonly: boolean;
+	db: number;
+	kind: IpcConnectionKind;
+	tls: IpcConnectionTls;
+	ssh: IpcConnectionSsh;
+	reconnect: IpcConnectionReconnect;
+	env: IpcConnectionEnv;
+	group?: IpcConnectionGroup['guid'];
 
 	guid: string;
 	createdAt: string;
@@ -26,6 +111,56 @@ export type IpcConnection = {
 
 export type IpcConnections = Array<IpcConnection>;
 
+export type IpcConnectionsBundle = {
+	version: number;
+	exportedAt: string;
+	connections: IpcConnections;
+};
+
+export type IpcImportConnectionsResult = {
+	imported: Array<IpcConnection['guid']>;
+	skipped: Array<IpcConnection['connectionName']>;
+};
+
+export type IpcConnectionTestResult = {
+	latency: number;
+	serverVersion?: string;
+	username?: string;
+};
+
+export enum IpcProtobufSchemaFileKind {
+	Proto = 'proto',
+	DescriptorSet = 'descriptorSet'
+}
+
+export type IpcProtobufSchemaFile = {
+	// The path used by the `import` statements.
+	name: string;
+	kind: IpcProtobufSchemaFileKind;
+	// The `.proto` source, or the base64 encoded descriptor set.
+	content: string;
+};
+
+export type IpcProtobufMessageMapping = {
+	// Glob-style, e.g. `user:*`.
+	pattern: string;
+	messageType: string;
+};
+
+export type IpcProtobufSchemas = {
+	files: Array<IpcProtobufSchemaFile>;
+	mappings: Array<IpcProtobufMessageMapping>;
+	guid: IpcConnection['guid'];
+};
+
+export type IpcConnectionSecrets = {
+	password?: string;
+	sshPassword?: string;
+	sshPassphrase?: string;
+	sentinelPassword?: string;
+	tlsClientKeyPassphrase?: string;
+};
+
 // Lowercase.
 export enum IpcResponseStatus {
 	Success = 'success',
@@ -41,34 +176,258 @@ export interface IpcResponse<D extends unknown = unknown> {
 
 export type IpcClientMetrics = Record<string, string>;
 
-export type IpcClientKeys = Array<string>;
+// `raw` is base64 encoded, `display` is a lossy UTF-8 string only for presenting.
+export type IpcRedisBytes = {
+	raw: string;
+	display: string;
+	binary: boolean;
+};
+
+// Plain strings are sent as UTF-8 texts.
+export type IpcKeyName = string | IpcRedisBytes;
+
+export type IpcClientKeys = Array<IpcRedisBytes>;
 
 export enum IpcKeyType {
 	String = 'String',
-	Hash = 'Hash'
+	Hash = 'Hash',
+	List = 'List',
+	Set = 'Set',
+	ZSet = 'ZSet',
+	Stream = 'Stream',
+	Json = 'Json'
 }
 
+export enum IpcKeyNamespaceNodeKind {
+	Folder = 'folder',
+	Key = 'key'
+}
+
+export type IpcKeyNamespaceNode = {
+	kind: IpcKeyNamespaceNodeKind;
+	// The segment after the expanded prefix.
+	name: IpcRedisBytes;
+	// Folders are expanded with it, which ends with the separator.
+	fullName: IpcRedisBytes;
+	// The keys under a folder scanned so far, always 1 for keys.
+	count: number;
+	// Only for keys, `Unknown` for the unsupported types.
+	keyType?: IpcKeyType | string;
+};
+
+// The nodes are folded from the current page only, the folders coming again replace the former ones.
+export type IpcKeyNamespacePage = {
+	nodes: Array<IpcKeyNamespaceNode>;
+	canContinue: boolean;
+};
+
+// Chosen by the caller, e.g. one per tab, idle scanners are closed after a while.
+export type IpcScanId = string;
+
+// `substring` escapes the glob characters of the input, `exact` uses it as the glob pattern.
+export type IpcScanPatternMode = 'substring' | 'exact';
+
+export type IpcScanFilter = {
+	mode?: IpcScanPatternMode;
+	// Filtered by the server, `Json` is not supported.
+	keyType?: IpcKeyType | string;
+	// Checked on the scanned keys, a page may be returned with fewer keys.
+	regex?: string;
+};
+
+export enum IpcScannerKind {
+	Keys = 'keys',
+	Hash = 'hash',
+	Set = 'set',
+	ZSet = 'zset'
+}
+
+export type IpcScannerOptions = {
+	kind: IpcScannerKind;
+	// Required except for the keys.
+	keyName?: IpcKeyName;
+	// Glob-style, all by default. Built by the filter mode if a filter is given.
+	pattern?: string;
+	// The key type and the regex only apply to the keys, and are kept when continuing.
+	filter?: IpcScanFilter;
+};
+
+// The items are of the scanner kind.
+export type IpcScanPage = {
+	kind: IpcScannerKind;
+	items: IpcClientKeys | IpcHashKeyValues | IpcSetKeyMembers | Array<IpcZSetKeyMember>;
+	canContinue: boolean;
+};
+
+// Reported at most twice a second, and whenever a page is returned.
+export type IpcScanProgressPayload = {
+	guid: IpcConnection['guid'];
+	scanId: IpcScanId;
+	kind: IpcScannerKind;
+	// An item may be returned more than once.
+	scannedCount: number;
+	iterations: number;
+	// By DBSIZE or the length of the key, unknown in a cluster.
+	total?: number;
+	percent?: number;
+	finished: boolean;
+};
+
 export type SaveIpcNewKeyPayload = {
 	name: string;
 	type: IpcKeyType | string;
 };
 
 export type SetIpcKeyTTLPayload = {
-	name: string;
+	name: IpcKeyName;
 	ttl: number;
 };
 
 export type SetIpcKeyContentTypeStringPayload = {
-	name: string;
-	content: string;
+	name: IpcKeyName;
+	content: string | IpcRedisBytes;
 };
 
 export type IpcRenameKeyPayload = {
+	name: IpcKeyName;
+	newName: IpcKeyName;
+};
+
+export type IpcListKeyPage = {
+	items: Array<IpcRedisBytes>;
+	total: number;
+};
+
+export type IpcSetKeyMembers = Array<IpcRedisBytes>;
+
+// Plain strings are sent as UTF-8 texts, same as the key names.
+export type IpcMemberName = string | IpcRedisBytes;
+
+export type IpcZSetKeyMember = {
+	member: IpcRedisBytes;
+	score: number;
+};
+
+export type IpcNewZSetKeyMember = {
+	member: IpcMemberName;
+	score: number;
+};
+
+export type IpcZSetKeyPage = {
+	items: Array<IpcZSetKeyMember>;
+	total: number;
+};
+
+// Bounds are always from the lower one, even if reversed.
+export type IpcZSetKeyRange = {
+	min: string;
+	max: string;
+	offset?: number;
+	count?: number;
+	rev?: boolean;
+};
+
+export type IpcStreamKeyField = {
+	name: IpcRedisBytes;
+	value: IpcRedisBytes;
+};
+
+export type IpcNewStreamKeyField = {
+	name: IpcMemberName;
+	value: IpcMemberName;
+};
+
+export type IpcStreamKeyEntry = {
+	id: string;
+	fields: Array<IpcStreamKeyField>;
+};
+
+export type IpcStreamKeyPage = {
+	entries: Array<IpcStreamKeyEntry>;
+	total: number;
+};
+
+export type IpcStreamKeyGroup = {
 	name: string;
-	newName: string;
+	consumers: number;
+	pending: number;
+	lastDeliveredId: string;
+	entriesRead?: number;
+	lag?: number;
 };
 
-export type IpcHashKeyValues = Array<{
+export type IpcStreamKeyConsumer = {
 	name: string;
-	value: string;
+	pending: number;
+	idle: number;
+	inactive?: number;
+};
+
+export type IpcStreamKeyPendingSummary = {
+	count: number;
+	smallestId?: string;
+	greatestId?: string;
+	consumers: Array<[string, number]>;
+};
+
+export type IpcStreamKeyPendingEntry = {
+	id: string;
+	consumer: string;
+	idle: number;
+	deliveryCount: number;
+};
+
+export type IpcStreamKeyPendingQuery = {
+	start?: string;
+	end?: string;
+	count: number;
+	consumer?: string;
+	minIdleTime?: number;
+};
+
+export type IpcStreamKeyAutoClaimQuery = {
+	consumer: string;
+	minIdleTime: number;
+	start?: string;
+	count?: number;
+};
+
+export type IpcStreamKeyAutoClaimResult = {
+	nextId: string;
+	entries: Array<IpcStreamKeyEntry>;
+	deletedIds: Array<string>;
+};
+
+// The format and compression are detected when not specified.
+export type IpcValueFormatOptions = {
+	format?: string;
+	compression?: string;
+};
+
+export type IpcFormattedValue = {
+	format: string;
+	compression: string;
+	content: string;
+};
+
+export type IpcHashKeyFormattedField = {
+	name: IpcKeyName;
+	content: string;
+};
+
+export type IpcValueFormatters = {
+	formats: Array<string>;
+	compressions: Array<string>;
+};
+
+export type IpcHashKeyValues = Array<{
+	name: IpcRedisBytes;
+	value: IpcRedisBytes;
 }>;
+
+export type IpcKeyspaceInfo = {
+	db: number;
+	keys: number;
+	expires: number;
+	avgTtl: number;
+};
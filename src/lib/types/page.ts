@@ -1,4 +1,4 @@
-import type { IpcClientKeys, IpcClientMetrics, IpcConnection } from '$lib/types/ipc';
+import type { IpcClientKeys, IpcClientMetrics, IpcConnection, IpcRedisBytes } from '$lib/types/ipc';
 
 export enum PageTheme {
 	Light = 'Light',
@@ -35,7 +35,7 @@ export type MainTab =
 	| {
 			type: MainTabType.KeyDetail;
 			data: {
-				key: string;
+				key: IpcRedisBytes;
 				db: number;
 				connectionInfo: IpcConnection;
 			};